@@ -0,0 +1,206 @@
+// 웹 빌드가 쓰는 CORS 우회 프록시.
+// "http://host:port/https://translate.google.com/m?..." 처럼 경로에 붙은 주소로 요청을 그대로 넘김
+use anyhow::{anyhow, Error};
+use hyper::body::HttpBody;
+use hyper::header::{HeaderValue, CONTENT_RANGE, CONTENT_TYPE, RANGE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use shared_constants::PROXY_ERROR_HEADER;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+// 같은 네트워크의 다른 기기가 열린 중계기로 쓰지 못하도록 기본은 이 컴퓨터에서만 받음
+const DEFAULT_ADDR: &str = "127.0.0.1:9080";
+const UPSTREAM_TIMEOUT: Duration = Duration::from_secs(60);
+const MAX_REDIRECTS: usize = 5;
+// 카드 업로드를 받을 만큼만. 넘으면 413
+const MAX_BODY: usize = 64 * 1024 * 1024;
+
+// 번역 페이지, catbox 업로드와 링크 점검 외에는 넘기지 않음
+const ALLOWED_HOSTS: &[&str] = &[
+    "translate.google.com",
+    "catbox.moe",
+    "litterbox.catbox.moe",
+    "files.catbox.moe",
+    "litter.catbox.moe",
+];
+
+// 경로에서 넘겨줄 주소를 꺼냄. 허용 목록에 없는 호스트는 거절
+fn upstream_url(path_and_query: &str) -> Result<reqwest::Url, Error> {
+    let url = reqwest::Url::parse(path_and_query.trim_start_matches('/'))
+        .map_err(|e| anyhow!("Invalid upstream URL: {e}"))?;
+    check_allowed(url)
+}
+
+fn check_allowed(url: reqwest::Url) -> Result<reqwest::Url, Error> {
+    if url.scheme() != "https" {
+        return Err(anyhow!("Only https upstreams are allowed"));
+    }
+    match url.host_str() {
+        Some(host) if ALLOWED_HOSTS.contains(&host) => Ok(url),
+        Some(host) => Err(anyhow!("{host} is not in the allowlist")),
+        None => Err(anyhow!("Missing upstream host")),
+    }
+}
+
+fn with_cors(mut response: Response<Body>) -> Response<Body> {
+    let headers = response.headers_mut();
+    headers.insert("access-control-allow-origin", HeaderValue::from_static("*"));
+    headers.insert(
+        "access-control-allow-methods",
+        HeaderValue::from_static("GET, HEAD, POST, OPTIONS"),
+    );
+    headers.insert(
+        "access-control-allow-headers",
+        HeaderValue::from_static("*"),
+    );
+    headers.insert(
+        "access-control-expose-headers",
+        HeaderValue::from_static(PROXY_ERROR_HEADER),
+    );
+    response
+}
+
+fn error_response(status: StatusCode, error: Error) -> Response<Body> {
+    eprintln!("{status}: {error}");
+    let mut response = Response::new(Body::from(error.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(PROXY_ERROR_HEADER, HeaderValue::from_static("1"));
+    response
+}
+
+async fn forward(client: &reqwest::Client, request: Request<Body>) -> Response<Body> {
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map(|p| p.as_str())
+        .unwrap_or_default();
+    let url = match upstream_url(path_and_query) {
+        Ok(url) => url,
+        Err(error) => return error_response(StatusCode::FORBIDDEN, error),
+    };
+    let method = match *request.method() {
+        Method::GET => reqwest::Method::GET,
+        Method::HEAD => reqwest::Method::HEAD,
+        Method::POST => reqwest::Method::POST,
+        _ => {
+            return error_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                anyhow!("Only GET, HEAD and POST are proxied"),
+            )
+        }
+    };
+    let content_type = request.headers().get(CONTENT_TYPE).cloned();
+    // 링크 점검은 첫 바이트만 받음
+    let range = request.headers().get(RANGE).cloned();
+    let body = match read_body(request.into_body()).await {
+        Ok(body) => body,
+        Err(error) => return error_response(StatusCode::PAYLOAD_TOO_LARGE, error),
+    };
+
+    println!("{method} {url}");
+    let mut upstream = client.request(method, url).body(body);
+    if let Some(content_type) = content_type {
+        upstream = upstream.header(reqwest::header::CONTENT_TYPE, content_type.as_bytes());
+    }
+    if let Some(range) = range {
+        upstream = upstream.header(reqwest::header::RANGE, range.as_bytes());
+    }
+    let upstream = match upstream.send().await {
+        Ok(upstream) => upstream,
+        Err(error) => return error_response(StatusCode::BAD_GATEWAY, error.into()),
+    };
+
+    let status = upstream.status().as_u16();
+    let headers = [CONTENT_TYPE, CONTENT_RANGE]
+        .into_iter()
+        .filter_map(|name| {
+            let value = upstream.headers().get(name.as_str())?;
+            Some((name, HeaderValue::from_bytes(value.as_bytes()).ok()?))
+        })
+        .collect::<Vec<_>>();
+    let body = match upstream.bytes().await {
+        Ok(body) => body,
+        Err(error) => return error_response(StatusCode::BAD_GATEWAY, error.into()),
+    };
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = StatusCode::from_u16(status).unwrap_or(StatusCode::BAD_GATEWAY);
+    response.headers_mut().extend(headers);
+    response
+}
+
+// 요청 본문을 MAX_BODY 까지만 읽음
+async fn read_body(mut body: Body) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![];
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk?;
+        if bytes.len() + chunk.len() > MAX_BODY {
+            return Err(anyhow!("The request body is larger than {MAX_BODY} bytes"));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+// 리다이렉트도 한 번마다 허용 목록을 다시 확인함
+fn client() -> Result<reqwest::Client, Error> {
+    let redirect = reqwest::redirect::Policy::custom(|attempt| {
+        if attempt.previous().len() >= MAX_REDIRECTS {
+            return attempt.error(anyhow!("Too many redirects"));
+        }
+        match check_allowed(attempt.url().clone()) {
+            Ok(_) => attempt.follow(),
+            Err(error) => attempt.error(error),
+        }
+    });
+    Ok(reqwest::Client::builder()
+        .redirect(redirect)
+        .timeout(UPSTREAM_TIMEOUT)
+        .build()?)
+}
+
+async fn handle(
+    client: reqwest::Client,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() == Method::OPTIONS {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::NO_CONTENT;
+        return Ok(with_cors(response));
+    }
+    Ok(with_cors(forward(&client, request).await))
+}
+
+// 주소는 첫 번째 인자나 CORS_PROXY_ADDR 로 바꿀 수 있음
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    let addr: SocketAddr = std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var("CORS_PROXY_ADDR").ok())
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string())
+        .parse()?;
+    let client = client()?;
+
+    let make_service = make_service_fn(move |_| {
+        let client = client.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |request| handle(client.clone(), request))) }
+    });
+    println!("CORS proxy listening on http://{addr}/");
+    Server::bind(&addr).serve(make_service).await?;
+    Ok(())
+}
+
+#[test]
+fn test_upstream_url() {
+    let url = upstream_url("/https://translate.google.com/m?tl=en&sl=ko&q=%EA%B3%A0").unwrap();
+    assert_eq!(url.host_str(), Some("translate.google.com"));
+    assert_eq!(url.query(), Some("tl=en&sl=ko&q=%EA%B3%A0"));
+    assert!(upstream_url("/https://catbox.moe/user/api.php").is_ok());
+    assert!(upstream_url("/https://files.catbox.moe/abc123.png").is_ok());
+    assert!(upstream_url("/https://example.com/").is_err());
+    assert!(upstream_url("/http://catbox.moe/user/api.php").is_err());
+    assert!(upstream_url("/https://catbox.moe.example.com/").is_err());
+}
@@ -0,0 +1,225 @@
+use crate::http::{client, send_with_retry, RateLimiter, TranslateError};
+use crate::pasring_and_translate;
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+
+// 로컬 서버는 구글보다 넉넉하게 받지만, 여러 필드를 한꺼번에 보내도 몰리지 않게 함
+static LOCAL_HTTP_LIMITER: RateLimiter = RateLimiter::new(10.0, 10.0);
+
+// 사전 번역은 태그나 짧은 이름에만 씀
+const DICTIONARY_MAX_CHARS: usize = 60;
+
+// 캐릭터 카드 태그에 자주 나오는 낱말 (ko, en, ja)
+const DICTIONARY: &[[&str; 3]] = &[
+    ["고양이", "cat", "猫"],
+    ["고양이수인", "catgirl", "猫耳"],
+    ["수인", "kemonomimi", "獣人"],
+    ["메이드", "maid", "メイド"],
+    ["집사", "butler", "執事"],
+    ["여성", "female", "女性"],
+    ["남성", "male", "男性"],
+    ["소녀", "girl", "少女"],
+    ["소년", "boy", "少年"],
+    ["학생", "student", "学生"],
+    ["선생님", "teacher", "先生"],
+    ["기사", "knight", "騎士"],
+    ["마법사", "mage", "魔法使い"],
+    ["엘프", "elf", "エルフ"],
+    ["악마", "demon", "悪魔"],
+    ["천사", "angel", "天使"],
+    ["용", "dragon", "ドラゴン"],
+    ["판타지", "fantasy", "ファンタジー"],
+    ["현대", "modern", "現代"],
+    ["로맨스", "romance", "ロマンス"],
+    ["코미디", "comedy", "コメディ"],
+    ["공포", "horror", "ホラー"],
+    ["츤데레", "tsundere", "ツンデレ"],
+    ["얀데레", "yandere", "ヤンデレ"],
+];
+
+// 번역에 쓸 수 있는 엔진. Translator 는 목록 순서대로 시도하고 실패하면 다음 것으로 넘어감
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Backend {
+    Google,
+    // LibreTranslate/Argos 호환 서버 (POST {url}/translate). 로컬 서버라 프록시를 거치지 않음
+    LocalHttp { url: String },
+    // 원문을 stdin 으로 받아 번역문을 stdout 으로 내는 명령. 인자의 {from}, {to} 는 언어 코드로 바뀜
+    Command { program: String, args: String },
+    // 내장 사전으로 낱말만 바꿈 (용어집은 Translator 가 먼저 적용). 모르는 낱말은 원문 그대로이고,
+    // 아는 낱말이 하나도 없으면 실패
+    Dictionary,
+}
+
+impl Backend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Backend::Google => "Google",
+            Backend::LocalHttp { .. } => "Local HTTP",
+            Backend::Command { .. } => "Command",
+            Backend::Dictionary => "Dictionary",
+        }
+    }
+
+    // 낱말만 바꾼 대체 번역이라 번역 메모리에 남기면 안 되는 엔진
+    pub fn is_fallback(&self) -> bool {
+        matches!(self, Backend::Dictionary)
+    }
+
+    pub(crate) async fn translate(
+        &self,
+        text: String,
+        from: &str,
+        to: &str,
+        proxy: Option<&str>,
+    ) -> Result<String, Error> {
+        match self {
+            Backend::Google => pasring_and_translate(text, from, to, proxy).await,
+            Backend::LocalHttp { url } => translate_local_http(url, text, from, to).await,
+            Backend::Command { program, args } => {
+                translate_command(program, args, text, from, to).await
+            }
+            Backend::Dictionary => translate_dictionary(&text, from, to),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LocalRequest<'a> {
+    q: &'a str,
+    source: &'a str,
+    target: &'a str,
+    format: &'a str,
+}
+
+#[derive(Deserialize)]
+struct LocalResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+async fn translate_local_http(
+    url: &str,
+    text: String,
+    from: &str,
+    to: &str,
+) -> Result<String, Error> {
+    let request = LocalRequest {
+        q: &text,
+        source: from,
+        target: to,
+        format: "text",
+    };
+    let url = format!("{}/translate", url.trim_end_matches('/'));
+    let response =
+        send_with_retry(&LOCAL_HTTP_LIMITER, || client().post(&url).json(&request)).await?;
+    let response: LocalResponse = response.json().await.map_err(TranslateError::from)?;
+    Ok(response.translated_text)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn translate_command(
+    program: &str,
+    args: &str,
+    text: String,
+    from: &str,
+    to: &str,
+) -> Result<String, Error> {
+    use std::process::Stdio;
+    use tokio::io::AsyncWriteExt;
+
+    let args = args
+        .split_whitespace()
+        .map(|arg| arg.replace("{from}", from).replace("{to}", to));
+    let mut child = tokio::process::Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run {program}: {e}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes()).await?;
+    }
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "{program} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn translate_command(
+    program: &str,
+    _args: &str,
+    _text: String,
+    _from: &str,
+    _to: &str,
+) -> Result<String, Error> {
+    Err(anyhow!("Can't run {program} in the browser"))
+}
+
+fn dictionary_word(word: &str, from: &str, to: &str) -> Option<&'static str> {
+    let column = |language: &str| ["ko", "en", "ja"].iter().position(|l| *l == language);
+    let (from, to) = (column(from)?, column(to)?);
+    DICTIONARY
+        .iter()
+        .find(|row| row[from].eq_ignore_ascii_case(word))
+        .map(|row| row[to])
+}
+
+fn translate_dictionary(text: &str, from: &str, to: &str) -> Result<String, Error> {
+    if text.lines().count() > 1 || text.chars().count() > DICTIONARY_MAX_CHARS {
+        return Err(anyhow!("Too long for the dictionary fallback"));
+    }
+
+    // 낱말과 구분자를 번갈아 모아 구분자는 그대로 둠
+    let mut result = String::new();
+    let mut word = String::new();
+    let mut matched = false;
+    let mut flush = |word: &mut String, result: &mut String| {
+        match dictionary_word(word, from, to) {
+            Some(translated) => {
+                matched = true;
+                result.push_str(translated);
+            }
+            None => result.push_str(word),
+        }
+        word.clear();
+    };
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut word, &mut result);
+    if !matched {
+        return Err(anyhow!("No known words for the dictionary fallback"));
+    }
+    Ok(result)
+}
+
+#[test]
+fn test_dictionary_backend() -> Result<(), Error> {
+    assert_eq!(
+        translate_dictionary("유즈 (메이드, 고양이수인)", "ko", "en")?,
+        "유즈 (maid, catgirl)"
+    );
+    assert_eq!(
+        translate_dictionary("Maid, Elf", "en", "ko")?,
+        "메이드, 엘프"
+    );
+    assert!(translate_dictionary("first line\nsecond line", "ko", "en").is_err());
+    // 모르는 낱말뿐이면 원문을 번역으로 돌려주지 않음
+    assert!(translate_dictionary("유즈", "ko", "en").is_err());
+    Ok(())
+}
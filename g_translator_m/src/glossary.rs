@@ -0,0 +1,146 @@
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+
+// 번역기를 거치는 동안 용어를 보호하기 위한 표식 (줄바꿈용 "\\zzab" 과 같은 방식)
+const MARKER: &str = "\\zzg";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryEntry {
+    pub from: String,
+    pub to: String,
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Glossary {
+    pub entries: Vec<GlossaryEntry>,
+}
+
+impl Glossary {
+    // 해당 언어쌍에 쓰이는 항목들, 긴 용어부터 (짧은 용어가 긴 용어의 일부를 먹지 않도록)
+    fn entries_for(&self, from: &str, to: &str) -> Vec<&GlossaryEntry> {
+        let mut entries = self
+            .entries
+            .iter()
+            .filter(|e| e.from == from && e.to == to && !e.source.is_empty())
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.source.chars().count()));
+        entries
+    }
+
+    // 텍스트 전체가 용어 하나와 같으면 번역기를 부를 필요가 없음
+    pub fn lookup(&self, text: &str, from: &str, to: &str) -> Option<String> {
+        self.entries_for(from, to)
+            .into_iter()
+            .find(|e| e.source == text.trim())
+            .map(|e| e.target.clone())
+    }
+
+    // 용어를 표식으로 바꾸고, 표식 순서대로 넣을 번역어를 돌려줌.
+    // 이미 넣은 표식을 짧은 용어가 다시 바꾸지 않도록 원문에서 한 번에 찾음
+    pub fn protect(&self, text: &str, from: &str, to: &str) -> (String, Vec<String>) {
+        let entries = self.entries_for(from, to);
+        let mut protected = String::with_capacity(text.len());
+        let mut used: Vec<&GlossaryEntry> = vec![];
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let Some(entry) = entries.iter().find(|e| rest.starts_with(&e.source)) else {
+                protected.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            };
+            let index = match used.iter().position(|u| u == entry) {
+                Some(index) => index,
+                None => {
+                    used.push(entry);
+                    used.len() - 1
+                }
+            };
+            protected.push_str(&format!("{}{}", MARKER, index));
+            rest = &rest[entry.source.len()..];
+        }
+        let targets = used.into_iter().map(|e| e.target.clone()).collect();
+        (protected, targets)
+    }
+
+    pub fn restore(&self, text: String, targets: &[String]) -> String {
+        // "\\zzg1" 이 "\\zzg10" 의 앞부분을 바꾸지 않도록 뒤에서부터
+        targets
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(text, |text, (i, target)| {
+                text.replace(&format!("{}{}", MARKER, i), target)
+            })
+    }
+
+    // 첫 줄은 "from,to,source,target" 헤더
+    pub fn from_delimited(data: &str, delimiter: u8) -> Result<Self, Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .trim(csv::Trim::All)
+            .from_reader(data.as_bytes());
+        let entries = reader
+            .deserialize()
+            .collect::<Result<Vec<GlossaryEntry>, _>>()
+            .map_err(|e| anyhow!("Invalid glossary file: {e}"))?;
+        Ok(Self { entries })
+    }
+
+    pub fn to_delimited(&self, delimiter: u8) -> Result<String, Error> {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .from_writer(vec![]);
+        for entry in &self.entries {
+            writer.serialize(entry)?;
+        }
+        if self.entries.is_empty() {
+            writer.write_record(["from", "to", "source", "target"])?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    // 불러온 항목 중 같은 언어쌍·용어가 이미 있으면 덮어씀
+    pub fn merge(&mut self, other: Glossary) {
+        for entry in other.entries {
+            match self
+                .entries
+                .iter_mut()
+                .find(|e| e.from == entry.from && e.to == entry.to && e.source == entry.source)
+            {
+                Some(existing) => existing.target = entry.target,
+                None => self.entries.push(entry),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_glossary_round_trip() -> Result<(), Error> {
+    let glossary = Glossary::from_delimited(
+        "from\tto\tsource\ttarget\nko\ten\t수인\tkemonomimi\nko\ten\t고양이수인\tcatgirl\n",
+        b'\t',
+    )?;
+    let (text, targets) = glossary.protect("고양이수인 메이드와 수인 집사", "ko", "en");
+    assert_eq!(text, "\\zzg0 메이드와 \\zzg1 집사");
+    assert_eq!(
+        glossary.restore("\\zzg0 maid and \\zzg1 butler".to_string(), &targets),
+        "catgirl maid and kemonomimi butler"
+    );
+    assert_eq!(glossary.lookup("수인", "ko", "en"), Some("kemonomimi".to_string()));
+    assert_eq!(glossary.lookup("수인", "en", "ko"), None);
+
+    // 한 글자 용어가 앞서 넣은 표식을 망가뜨리지 않아야 함
+    let short = Glossary::from_delimited(
+        "from,to,source,target\nen,ko,Yuzu,유즈\nen,ko,g,지\nen,ko,z,제\nen,ko,0,영\n",
+        b',',
+    )?;
+    let (text, targets) = short.protect("Yuzu g 0", "en", "ko");
+    assert_eq!(text, "\\zzg0 \\zzg1 \\zzg2");
+    assert_eq!(short.restore(text, &targets), "유즈 지 영");
+
+    let csv = glossary.to_delimited(b',')?;
+    assert_eq!(Glossary::from_delimited(&csv, b',')?.entries, glossary.entries);
+    Ok(())
+}
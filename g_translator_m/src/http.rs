@@ -0,0 +1,145 @@
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use web_time::Instant;
+
+const MAX_RETRIES: u32 = 4;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+#[cfg(not(target_arch = "wasm32"))]
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+#[cfg(not(target_arch = "wasm32"))]
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum TranslateError {
+    Http(reqwest::Error),
+    // 재시도 후에도 성공하지 못한 응답 코드
+    Status(u16),
+    // 응답 페이지에 번역 결과가 없음 (CAPTCHA, 페이지 구조 변경 등)
+    NoResult,
+}
+
+impl std::fmt::Display for TranslateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslateError::Http(e) => write!(f, "Translation request failed: {e}"),
+            TranslateError::Status(code) => write!(f, "Translation server returned {code}"),
+            TranslateError::NoResult => {
+                write!(f, "The response page doesn't contain a translation")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslateError {}
+
+impl From<reqwest::Error> for TranslateError {
+    fn from(e: reqwest::Error) -> Self {
+        TranslateError::Http(e)
+    }
+}
+
+// 모든 번역 요청이 같이 쓰는 클라이언트
+pub(crate) fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        let builder = reqwest::Client::builder();
+        // wasm 에서는 브라우저 fetch 가 타임아웃을 관리함
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = builder
+            .timeout(REQUEST_TIMEOUT)
+            .connect_timeout(CONNECT_TIMEOUT);
+        builder.build().expect("Failed to build the HTTP client")
+    })
+}
+
+// 초당 refill 개씩 채워지고 최대 capacity 개까지 쌓이는 토큰 버킷
+pub(crate) struct RateLimiter {
+    capacity: f64,
+    refill: f64,
+    state: Mutex<Option<(f64, Instant)>>,
+}
+
+impl RateLimiter {
+    pub(crate) const fn new(capacity: f64, refill: f64) -> Self {
+        Self {
+            capacity,
+            refill,
+            state: Mutex::new(None),
+        }
+    }
+
+    // 토큰을 하나 가져가거나, 다음 토큰까지 기다려야 할 시간을 돌려줌
+    fn try_take(&self, now: Instant) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+        let (tokens, last) = state.unwrap_or((self.capacity, now));
+        let tokens =
+            (tokens + now.duration_since(last).as_secs_f64() * self.refill).min(self.capacity);
+        if tokens >= 1.0 {
+            *state = Some((tokens - 1.0, now));
+            None
+        } else {
+            *state = Some((tokens, now));
+            Some(Duration::from_secs_f64((1.0 - tokens) / self.refill))
+        }
+    }
+
+    pub(crate) async fn acquire(&self) {
+        while let Some(wait) = self.try_take(Instant::now()) {
+            sleep(wait).await;
+        }
+    }
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+// 429/5xx 와 연결 오류는 지수적으로 늘어나는 간격을 두고 다시 시도.
+// request 는 시도할 때마다 새 요청을 만듦
+pub(crate) async fn send_with_retry(
+    limiter: &RateLimiter,
+    request: impl Fn() -> reqwest::RequestBuilder,
+) -> Result<reqwest::Response, TranslateError> {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 0;
+    loop {
+        limiter.acquire().await;
+        let last_attempt = attempt == MAX_RETRIES;
+        match request().send().await {
+            Ok(response) if response.status().is_success() => return Ok(response),
+            Ok(response) if !last_attempt && is_retryable(response.status()) => (),
+            Ok(response) => return Err(TranslateError::Status(response.status().as_u16())),
+            Err(e) if !last_attempt && (e.is_timeout() || e.is_request()) => (),
+            Err(e) => return Err(e.into()),
+        }
+        sleep(backoff).await;
+        backoff *= 2;
+        attempt += 1;
+    }
+}
+
+pub(crate) async fn get_with_retry(
+    limiter: &RateLimiter,
+    url: &str,
+    query: &[(&str, &str)],
+) -> Result<String, TranslateError> {
+    let response = send_with_retry(limiter, || client().get(url).query(query)).await?;
+    Ok(response.text().await?)
+}
+
+#[test]
+fn test_rate_limiter() {
+    let limiter = RateLimiter::new(2.0, 4.0);
+    let start = Instant::now();
+    assert_eq!(limiter.try_take(start), None);
+    assert_eq!(limiter.try_take(start), None);
+    assert_eq!(limiter.try_take(start), Some(Duration::from_millis(250)));
+    assert_eq!(limiter.try_take(start + Duration::from_millis(250)), None);
+}
@@ -0,0 +1,130 @@
+// 구글 번역 페이지에 한 번에 보낼 수 있는 글자 수
+pub const CHUNK_LIMIT: usize = 1900;
+
+// 문장이 끝나는 곳. 긴 줄은 여기서 먼저 자름
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '。', '！', '？'];
+
+// 나눈 조각. separator 는 번역문을 다음 조각과 이을 때 넣음
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub text: String,
+    pub separator: &'static str,
+}
+
+// 줄 단위로 limit 글자를 넘지 않게 자름. limit 보다 긴 줄은 문장이나 공백에서 더 자름
+pub fn split_chunks(text: &str, limit: usize) -> Vec<Chunk> {
+    let mut chunks = vec![];
+    let mut lines: Vec<&str> = vec![];
+    let mut count = 0;
+    let flush = |lines: &mut Vec<&str>, chunks: &mut Vec<Chunk>| {
+        if !lines.is_empty() {
+            chunks.push(Chunk {
+                text: lines.join("\n"),
+                separator: "\n",
+            });
+            lines.clear();
+        }
+    };
+
+    for line in text.lines() {
+        if line.chars().count() > limit {
+            flush(&mut lines, &mut chunks);
+            chunks.extend(split_line(line, limit));
+            count = 0;
+            continue;
+        }
+        count += line.chars().count();
+        if count >= limit && !lines.is_empty() {
+            flush(&mut lines, &mut chunks);
+            count = line.chars().count();
+        }
+        lines.push(line);
+    }
+    flush(&mut lines, &mut chunks);
+    if chunks.is_empty() {
+        chunks.push(Chunk {
+            text: String::new(),
+            separator: "\n",
+        });
+    }
+    chunks
+}
+
+// 긴 줄 하나를 문장 끝, 없으면 공백, 그것도 없으면 limit 글자에서 자름.
+// 마지막 조각 뒤에는 줄바꿈이 옴
+fn split_line(line: &str, limit: usize) -> Vec<Chunk> {
+    let mut chunks = vec![];
+    let mut rest = line;
+    while rest.chars().count() > limit {
+        let end = rest
+            .char_indices()
+            .nth(limit)
+            .map_or(rest.len(), |(i, _)| i);
+        let window = &rest[..end];
+        let cut = window
+            .rfind(SENTENCE_ENDS)
+            .map(|i| i + window[i..].chars().next().map_or(0, char::len_utf8))
+            .filter(|cut| *cut > end / 2)
+            .or_else(|| rest[end..].starts_with(char::is_whitespace).then_some(end))
+            .or_else(|| window.rfind(char::is_whitespace).filter(|i| *i > 0))
+            .unwrap_or(end);
+        let (piece, next) = rest.split_at(cut);
+        let trimmed = next.trim_start();
+        let separator = if trimmed.len() < next.len() { " " } else { "" };
+        chunks.push(Chunk {
+            text: piece.trim_end().to_string(),
+            separator,
+        });
+        rest = trimmed;
+    }
+    chunks.push(Chunk {
+        text: rest.to_string(),
+        separator: "\n",
+    });
+    chunks
+}
+
+// 조각마다 번역한 글을 구분자로 다시 이음
+pub fn join_chunks(chunks: &[Chunk], translated: &[String]) -> String {
+    let mut joined = String::new();
+    for (i, text) in translated.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(chunks[i - 1].separator);
+        }
+        joined.push_str(text);
+    }
+    joined
+}
+
+#[test]
+fn test_split_chunks() {
+    let texts = |chunks: Vec<Chunk>| chunks.into_iter().map(|c| c.text).collect::<Vec<_>>();
+    assert_eq!(
+        texts(split_chunks("short\ntext", CHUNK_LIMIT)),
+        vec!["short\ntext"]
+    );
+    assert_eq!(texts(split_chunks("", CHUNK_LIMIT)), vec![""]);
+    assert_eq!(
+        texts(split_chunks("aaaa\nbbbb\ncccc\ndd", 10)),
+        vec!["aaaa\nbbbb", "cccc\ndd"]
+    );
+
+    // limit 보다 긴 한 줄은 문장 끝, 공백, 글자 수 순서로 자르고 이으면 원문이 됨
+    let text = "aaaa\nOne two. Three four five\nbb\n가나다라마바사아자차카타";
+    let chunks = split_chunks(text, 10);
+    assert_eq!(
+        texts(chunks.clone()),
+        vec![
+            "aaaa",
+            "One two.",
+            "Three four",
+            "five",
+            "bb",
+            "가나다라마바사아자차",
+            "카타"
+        ]
+    );
+    assert!(chunks.iter().all(|chunk| chunk.text.chars().count() <= 10));
+    let translated = chunks.iter().map(|c| c.text.clone()).collect::<Vec<_>>();
+    assert_eq!(join_chunks(&chunks, &translated), text);
+}
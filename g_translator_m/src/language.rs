@@ -0,0 +1,132 @@
+// 지원하는 언어 코드와 표시 이름
+pub const LANGUAGES: &[(&str, &str)] = &[
+    ("ko", "한국어"),
+    ("en", "English"),
+    ("ja", "日本語"),
+    ("zh", "中文"),
+    ("es", "Español"),
+    ("fr", "Français"),
+    ("de", "Deutsch"),
+    ("ru", "Русский"),
+];
+
+// 라틴 문자 언어를 구분하기 위한 자주 쓰이는 단어들
+const STOPWORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &["the", "and", "is", "you", "of", "to", "a", "her", "his", "she", "he", "with", "in"],
+    ),
+    (
+        "es",
+        &["el", "la", "de", "que", "y", "en", "los", "es", "con", "una", "por", "su"],
+    ),
+    (
+        "fr",
+        &["le", "la", "de", "et", "les", "est", "une", "des", "avec", "elle", "il", "pour"],
+    ),
+    (
+        "de",
+        &["der", "die", "und", "ist", "das", "sie", "er", "mit", "ein", "eine", "nicht", "zu"],
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub language: &'static str,
+    pub confidence: f32,
+}
+
+#[derive(Default)]
+struct ScriptCount {
+    hangul: usize,
+    kana: usize,
+    han: usize,
+    cyrillic: usize,
+    latin: usize,
+}
+
+fn count_scripts(text: &str) -> ScriptCount {
+    let mut count = ScriptCount::default();
+    for c in text.chars() {
+        match c as u32 {
+            0xAC00..=0xD7A3 | 0x1100..=0x11FF | 0x3130..=0x318F => count.hangul += 1,
+            0x3040..=0x30FF => count.kana += 1,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF => count.han += 1,
+            0x0400..=0x04FF => count.cyrillic += 1,
+            _ if c.is_alphabetic() && (c.is_ascii() || ('\u{C0}'..='\u{24F}').contains(&c)) => {
+                count.latin += 1
+            }
+            _ => (),
+        }
+    }
+    count
+}
+
+// 라틴 문자로 쓰인 글은 자주 쓰이는 단어의 비율로 언어를 고름
+fn detect_latin(text: &str) -> (&'static str, f32) {
+    let words = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>();
+    let scores = STOPWORDS
+        .iter()
+        .map(|(lang, stopwords)| {
+            let hits = words.iter().filter(|w| stopwords.contains(&w.as_str())).count();
+            (*lang, hits)
+        })
+        .collect::<Vec<_>>();
+    let total = scores.iter().map(|(_, hits)| hits).sum::<usize>();
+    match scores.into_iter().max_by_key(|(_, hits)| *hits) {
+        Some((lang, hits)) if hits > 0 => (lang, hits as f32 / total as f32),
+        // 판단할 단어가 없으면 영어로 보되 확신도는 낮게
+        _ => ("en", 0.5),
+    }
+}
+
+// 문자 체계 통계로 언어를 추정함. 글자가 하나도 없으면 None
+pub fn detect_language(text: &str) -> Option<Detection> {
+    let count = count_scripts(text);
+    let total = count.hangul + count.kana + count.han + count.cyrillic + count.latin;
+    if total == 0 {
+        return None;
+    }
+
+    let cjk = count.kana + count.han;
+    let candidates = [
+        ("ko", count.hangul),
+        // 가나가 조금이라도 섞인 한자 글은 일본어로
+        ("ja", if count.kana > 0 { cjk } else { 0 }),
+        ("zh", if count.kana == 0 { count.han } else { 0 }),
+        ("ru", count.cyrillic),
+        ("latin", count.latin),
+    ];
+    let (script, letters) = candidates
+        .into_iter()
+        .max_by_key(|(_, letters)| *letters)
+        .unwrap();
+    let share = letters as f32 / total as f32;
+
+    let (language, confidence) = match script {
+        "latin" => {
+            let (language, confidence) = detect_latin(text);
+            (language, share * confidence)
+        }
+        _ => (script, share),
+    };
+    Some(Detection {
+        language,
+        confidence,
+    })
+}
+
+#[test]
+fn test_detect_language() {
+    let detect = |text: &str| detect_language(text).unwrap().language;
+    assert_eq!(detect("Yuzu\n수줍음이 많은 고양이 소녀 메이드입니다."), "ko");
+    assert_eq!(detect("ユズ\n恥ずかしがり屋の猫耳メイドです。"), "ja");
+    assert_eq!(detect("柚子\n害羞的猫娘女仆。"), "zh");
+    assert_eq!(detect("Zoë\nShe is a shy cat girl maid and she loves the garden."), "en");
+    assert_eq!(detect("Lucía\nElla es una criada tímida con orejas de gato y le gusta el jardín."), "es");
+    assert_eq!(detect_language("1234 !!"), None);
+}
@@ -1,51 +1,141 @@
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use scraper::{Html, Selector};
-#[cfg(target_arch = "wasm32")] 
-use shared_constants::PROXY;
-
-async fn translate(text: String, from: &str, to: &str) -> Result<String, Error> {
-    #[cfg(target_arch = "wasm32")] 
-    let url = format!(
-        "{}https://translate.google.com/m?tl={}&sl={}&q={}",
-        PROXY, to, from, text
-    );
-
-    #[cfg(not(target_arch = "wasm32"))]
-    let url = format!(
-        "https://translate.google.com/m?tl={}&sl={}&q={}",
-        to, from, text
-    );
-
-    let client = reqwest::Client::new();
-    let response = client
-        .get(url)
-        .send()
-        .await?
-        .text()
-        .await?;
 
-    let result = parse_document(response);
+mod backend;
+mod glossary;
+mod http;
+mod job;
+mod language;
+mod memory;
+mod romanize;
+use http::{get_with_retry, RateLimiter};
+pub use backend::Backend;
+pub use glossary::{Glossary, GlossaryEntry};
+pub use http::TranslateError;
+pub use job::{join_chunks, split_chunks, Chunk, CHUNK_LIMIT};
+pub use language::{detect_language, Detection, LANGUAGES};
+pub use memory::{Segment, TranslationMemory};
+pub use romanize::{hangulize, romanize};
 
-    Ok(result)
+// Google 번역 모바일 페이지에 보내는 요청 한도
+static GOOGLE_LIMITER: RateLimiter = RateLimiter::new(5.0, 2.0);
+
+// proxy 는 "http://host:port/" 처럼 요청 주소 앞에 붙는 CORS 우회 프록시
+async fn translate(
+    text: String,
+    from: &str,
+    to: &str,
+    proxy: Option<&str>,
+) -> Result<String, TranslateError> {
+    let url = format!("{}https://translate.google.com/m", proxy.unwrap_or_default());
+
+    let response = get_with_retry(
+        &GOOGLE_LIMITER,
+        &url,
+        &[("tl", to), ("sl", from), ("q", text.as_str())],
+    )
+    .await?;
+
+    parse_document(response)
 }
 
-fn parse_document(res: String) -> String {
+fn parse_document(res: String) -> Result<String, TranslateError> {
     let fragment = Html::parse_document(&res);
     let selector = Selector::parse(".result-container").expect("Parsing failed");
     let result = fragment
         .select(&selector)
         .next()
-        .unwrap()
+        .ok_or(TranslateError::NoResult)?
         .text()
         .collect::<Vec<_>>()
         .join("");
-    result
+    Ok(result)
 }
 
-pub async fn pasring_and_translate(text: String, from: &str, to: &str) -> Result<String, Error> {
+pub async fn pasring_and_translate(
+    text: String,
+    from: &str,
+    to: &str,
+    proxy: Option<&str>,
+) -> Result<String, Error> {
     let text = text.replace("\r\n", "\\zzab").replace('\n', "\\zzab");
 
-    let translated_text = translate(text, from, to).await?;
+    let translated_text = translate(text, from, to, proxy).await?;
     let translated_text = translated_text.replace("\\zzab", "\n");
     Ok(translated_text)
 }
+
+// 번역 결과. fallback 이면 사전처럼 낱말만 바꾼 것이라 번역 메모리에 남기지 않음
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    pub text: String,
+    pub fallback: bool,
+}
+
+// 번역 메모리에서 먼저 찾고, 없으면 용어집을 앞뒤로 적용해 backends 를 차례로 시도
+#[derive(Debug, Clone)]
+pub struct Translator {
+    pub glossary: Glossary,
+    pub memory: TranslationMemory,
+    pub backends: Vec<Backend>,
+    pub proxy: Option<String>,
+}
+
+impl Default for Translator {
+    fn default() -> Self {
+        Self {
+            glossary: Glossary::default(),
+            memory: TranslationMemory::default(),
+            backends: vec![Backend::Google, Backend::Dictionary],
+            proxy: None,
+        }
+    }
+}
+
+impl Translator {
+    pub async fn translate(
+        &self,
+        text: String,
+        from: &str,
+        to: &str,
+    ) -> Result<Translation, Error> {
+        let found = |text: String| Translation {
+            text,
+            fallback: false,
+        };
+        if let Some(target) = self.memory.lookup(&text, from, to) {
+            return Ok(found(target));
+        }
+        if let Some(target) = self.glossary.lookup(&text, from, to) {
+            return Ok(found(target));
+        }
+        let (text, targets) = self.glossary.protect(&text, from, to);
+
+        let mut errors = vec![];
+        for backend in &self.backends {
+            match backend.translate(text.clone(), from, to, self.proxy.as_deref()).await {
+                Ok(translated) => {
+                    return Ok(Translation {
+                        text: self.glossary.restore(translated, &targets),
+                        fallback: backend.is_fallback(),
+                    })
+                }
+                Err(error) => errors.push(format!("{}: {}", backend.name(), error)),
+            }
+        }
+        if errors.is_empty() {
+            return Err(anyhow!("No translation backend is enabled"));
+        }
+        Err(anyhow!(errors.join(" / ")))
+    }
+}
+
+#[test]
+fn test_parse_document() {
+    let page = r#"<html><div class="result-container">Shy cat girl maid</div></html>"#;
+    assert_eq!(parse_document(page.to_string()).unwrap(), "Shy cat girl maid");
+    assert!(matches!(
+        parse_document("<html><form id=\"captcha-form\"></form></html>".to_string()),
+        Err(TranslateError::NoResult)
+    ));
+}
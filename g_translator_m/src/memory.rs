@@ -0,0 +1,416 @@
+use anyhow::{anyhow, Error};
+use quick_xml::events::{BytesDecl, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+use serde::{Deserialize, Serialize};
+
+// 설정과 함께 저장되므로 개수를 제한함. 넘치면 고치지 않은 오래된 세그먼트부터 지움
+const MAX_SEGMENTS: usize = 5000;
+
+// 원문 하나와 그 기계 번역, 사람이 고친 번역
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub from: String,
+    pub to: String,
+    pub source: String,
+    pub machine: String,
+    pub edited: Option<String>,
+}
+
+impl Segment {
+    // 고친 번역이 있으면 그것을, 없으면 기계 번역을
+    pub fn best(&self) -> &str {
+        self.edited.as_deref().unwrap_or(&self.machine)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TranslationMemory {
+    pub segments: Vec<Segment>,
+}
+
+impl TranslationMemory {
+    fn find_mut(&mut self, from: &str, to: &str, source: &str) -> Option<&mut Segment> {
+        self.segments
+            .iter_mut()
+            .find(|s| s.from == from && s.to == to && s.source == source)
+    }
+
+    // 원문과 정확히 같은 세그먼트가 있으면 기계 번역 대신 사용
+    pub fn lookup(&self, text: &str, from: &str, to: &str) -> Option<String> {
+        self.segments
+            .iter()
+            .find(|s| s.from == from && s.to == to && s.source == text)
+            .map(|s| s.best().to_string())
+    }
+
+    // 기계 번역 결과를 기록함. 이미 고친 번역이 있으면 그대로 둠
+    pub fn record_machine(&mut self, from: &str, to: &str, source: &str, machine: &str) {
+        if source.trim().is_empty() || machine.trim().is_empty() {
+            return;
+        }
+        match self.find_mut(from, to, source) {
+            // 메모리에서 꺼내 쓴 번역이 다시 들어온 경우는 건너뜀
+            Some(segment) if segment.best() == machine => (),
+            Some(segment) => segment.machine = machine.to_string(),
+            None => self.segments.push(Segment {
+                from: from.to_string(),
+                to: to.to_string(),
+                source: source.to_string(),
+                machine: machine.to_string(),
+                edited: None,
+            }),
+        }
+        self.truncate(MAX_SEGMENTS);
+    }
+
+    fn truncate(&mut self, limit: usize) {
+        while self.segments.len() > limit {
+            let oldest = self.segments.iter().position(|s| s.edited.is_none());
+            self.segments.remove(oldest.unwrap_or(0));
+        }
+    }
+
+    // 기계 번역과 달라진 경우에만 고친 번역으로 기록함
+    pub fn record_edit(&mut self, from: &str, to: &str, source: &str, edited: &str) {
+        if let Some(segment) = self.find_mut(from, to, source) {
+            if segment.machine != edited && !edited.trim().is_empty() {
+                segment.edited = Some(edited.to_string());
+            } else if segment.machine == edited {
+                segment.edited = None;
+            }
+        }
+    }
+
+    // 불러온 세그먼트 중 같은 언어쌍·원문이 이미 있으면 덮어씀
+    pub fn merge(&mut self, other: TranslationMemory) {
+        for segment in other.segments {
+            match self.find_mut(&segment.from, &segment.to, &segment.source) {
+                Some(existing) => *existing = segment,
+                None => self.segments.push(segment),
+            }
+        }
+        self.truncate(MAX_SEGMENTS);
+    }
+
+    // TMX 1.4. 번역 단위마다 원문과 최종 번역을 담고, 기계 번역은 prop 으로 남김
+    pub fn to_tmx(&self) -> Result<String, Error> {
+        let mut writer = Writer::new_with_indent(vec![], b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        writer
+            .create_element("tmx")
+            .with_attribute(("version", "1.4"))
+            .write_inner_content(|writer| {
+                writer
+                    .create_element("header")
+                    .with_attributes([
+                        ("creationtool", "CharacterWikiGen"),
+                        ("creationtoolversion", env!("CARGO_PKG_VERSION")),
+                        ("segtype", "paragraph"),
+                        ("o-tmf", "g_translator_m"),
+                        ("adminlang", "en"),
+                        ("srclang", "*all*"),
+                        ("datatype", "plaintext"),
+                    ])
+                    .write_empty()?;
+                writer
+                    .create_element("body")
+                    .write_inner_content(|writer| {
+                        for segment in &self.segments {
+                            let tu = writer
+                                .create_element("tu")
+                                .with_attribute(("srclang", segment.from.as_str()));
+                            tu.write_inner_content(|writer| {
+                                writer
+                                    .create_element("prop")
+                                    .with_attribute(("type", "x-machine-translation"))
+                                    .write_text_content(BytesText::new(&segment.machine))?;
+                                write_tuv(writer, &segment.from, &segment.source)?;
+                                write_tuv(writer, &segment.to, segment.best())?;
+                                Ok(())
+                            })?;
+                        }
+                        Ok(())
+                    })?;
+                Ok(())
+            })?;
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub fn from_tmx(data: &str) -> Result<Self, Error> {
+        let mut memory = TranslationMemory::default();
+        let mut reader = Reader::from_str(data);
+        // (언어, 내용) 목록
+        let mut tuvs: Vec<(String, String)> = vec![];
+        let mut machine = None;
+        let mut is_machine_prop = false;
+        let mut language = String::new();
+        // 지금 읽고 있는 seg/prop 의 내용
+        let mut text: Option<String> = None;
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) => match e.name().as_ref() {
+                    b"tu" => {
+                        tuvs.clear();
+                        machine = None;
+                    }
+                    b"tuv" => language = attribute(&e, "xml:lang")?.unwrap_or_default(),
+                    b"seg" => text = Some(String::new()),
+                    b"prop" => {
+                        is_machine_prop =
+                            attribute(&e, "type")?.as_deref() == Some("x-machine-translation");
+                        text = Some(String::new());
+                    }
+                    _ => (),
+                },
+                Event::Text(e) => {
+                    if let Some(text) = text.as_mut() {
+                        text.push_str(&e.unescape()?);
+                    }
+                }
+                Event::CData(e) => {
+                    if let Some(text) = text.as_mut() {
+                        text.push_str(std::str::from_utf8(&e)?);
+                    }
+                }
+                Event::End(e) => match e.name().as_ref() {
+                    b"seg" => {
+                        if let Some(text) = text.take() {
+                            tuvs.push((std::mem::take(&mut language), text));
+                        }
+                    }
+                    // 다른 도구가 만든 prop 은 무시
+                    b"prop" => machine = text.take().filter(|_| is_machine_prop),
+                    b"tu" => {
+                        if let [(from, source), (to, target), ..] = tuvs.as_slice() {
+                            let machine = machine.take().unwrap_or_else(|| target.clone());
+                            memory.segments.push(Segment {
+                                from: from.clone(),
+                                to: to.clone(),
+                                source: source.clone(),
+                                edited: (*target != machine).then(|| target.clone()),
+                                machine,
+                            });
+                        }
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+        Ok(memory)
+    }
+
+    // XLIFF 1.2. 언어쌍마다 file 하나, 기계 번역은 alt-trans 로 남김
+    pub fn to_xliff(&self) -> Result<String, Error> {
+        let mut pairs: Vec<(&str, &str)> = vec![];
+        for segment in &self.segments {
+            let pair = (segment.from.as_str(), segment.to.as_str());
+            if !pairs.contains(&pair) {
+                pairs.push(pair);
+            }
+        }
+
+        let mut writer = Writer::new_with_indent(vec![], b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        writer
+            .create_element("xliff")
+            .with_attributes([
+                ("version", "1.2"),
+                ("xmlns", "urn:oasis:names:tc:xliff:document:1.2"),
+            ])
+            .write_inner_content(|writer| {
+                for (from, to) in &pairs {
+                    writer
+                        .create_element("file")
+                        .with_attributes([
+                            ("original", "character-card"),
+                            ("datatype", "plaintext"),
+                            ("source-language", *from),
+                            ("target-language", *to),
+                        ])
+                        .write_inner_content(|writer| {
+                            writer
+                                .create_element("body")
+                                .write_inner_content(|writer| {
+                                    let segments = self
+                                        .segments
+                                        .iter()
+                                        .filter(|s| s.from == *from && s.to == *to);
+                                    for (i, segment) in segments.enumerate() {
+                                        write_trans_unit(writer, i, segment)?;
+                                    }
+                                    Ok(())
+                                })?;
+                            Ok(())
+                        })?;
+                }
+                Ok(())
+            })?;
+        Ok(String::from_utf8(writer.into_inner())?)
+    }
+
+    pub fn from_xliff(data: &str) -> Result<Self, Error> {
+        let mut memory = TranslationMemory::default();
+        let mut reader = Reader::from_str(data);
+        let (mut from, mut to) = (String::new(), String::new());
+        let (mut source, mut target, mut machine) = (None, None, None);
+        let mut in_alt_trans = false;
+        // 지금 읽고 있는 source/target 의 내용
+        let mut text: Option<String> = None;
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) => match e.name().as_ref() {
+                    b"file" => {
+                        from = attribute(&e, "source-language")?.unwrap_or_default();
+                        to = attribute(&e, "target-language")?.unwrap_or_default();
+                    }
+                    b"trans-unit" => (source, target, machine) = (None, None, None),
+                    b"alt-trans" => in_alt_trans = true,
+                    b"source" | b"target" => text = Some(String::new()),
+                    _ => (),
+                },
+                Event::Text(e) => {
+                    if let Some(text) = text.as_mut() {
+                        text.push_str(&e.unescape()?);
+                    }
+                }
+                Event::CData(e) => {
+                    if let Some(text) = text.as_mut() {
+                        text.push_str(std::str::from_utf8(&e)?);
+                    }
+                }
+                Event::End(e) => match e.name().as_ref() {
+                    b"source" if !in_alt_trans => source = text.take(),
+                    b"target" if in_alt_trans => machine = text.take(),
+                    b"target" => target = text.take(),
+                    b"alt-trans" => in_alt_trans = false,
+                    b"trans-unit" => {
+                        if let (Some(source), Some(target)) = (source.take(), target.take()) {
+                            let machine = machine.take().unwrap_or_else(|| target.clone());
+                            memory.segments.push(Segment {
+                                from: from.clone(),
+                                to: to.clone(),
+                                source,
+                                edited: (target != machine).then_some(target),
+                                machine,
+                            });
+                        }
+                    }
+                    _ => (),
+                },
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+        Ok(memory)
+    }
+
+    // 확장자나 내용으로 형식을 골라 불러옴
+    pub fn import(data: &str) -> Result<Self, Error> {
+        if data.contains("<tmx") {
+            Self::from_tmx(data)
+        } else if data.contains("<xliff") {
+            Self::from_xliff(data)
+        } else {
+            Err(anyhow!("Not a TMX or XLIFF document"))
+        }
+    }
+}
+
+fn attribute(e: &BytesStart, name: &str) -> Result<Option<String>, Error> {
+    Ok(match e.try_get_attribute(name)? {
+        Some(attribute) => Some(attribute.unescape_value()?.into_owned()),
+        None => None,
+    })
+}
+
+fn write_tuv(writer: &mut Writer<Vec<u8>>, language: &str, text: &str) -> quick_xml::Result<()> {
+    writer
+        .create_element("tuv")
+        .with_attribute(("xml:lang", language))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("seg")
+                .write_text_content(BytesText::new(text))?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+fn write_trans_unit(
+    writer: &mut Writer<Vec<u8>>,
+    id: usize,
+    segment: &Segment,
+) -> quick_xml::Result<()> {
+    let id = id.to_string();
+    writer
+        .create_element("trans-unit")
+        .with_attribute(("id", id.as_str()))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("source")
+                .write_text_content(BytesText::new(&segment.source))?;
+            let state = match segment.edited {
+                Some(_) => "translated",
+                None => "needs-review-translation",
+            };
+            writer
+                .create_element("target")
+                .with_attribute(("state", state))
+                .write_text_content(BytesText::new(segment.best()))?;
+            writer
+                .create_element("alt-trans")
+                .with_attribute(("origin", "machine"))
+                .write_inner_content(|writer| {
+                    writer
+                        .create_element("target")
+                        .write_text_content(BytesText::new(&segment.machine))?;
+                    Ok(())
+                })?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+#[test]
+fn test_memory_round_trip() -> Result<(), Error> {
+    let mut memory = TranslationMemory::default();
+    memory.record_machine("ko", "en", "수줍은 고양이 메이드", "Shy cat maid");
+    memory.record_machine(
+        "ko",
+        "ja",
+        "수줍은 고양이 메이드",
+        "恥ずかしがり屋の猫メイド",
+    );
+    memory.record_edit(
+        "ko",
+        "en",
+        "수줍은 고양이 메이드",
+        "A shy catgirl maid & <butler>",
+    );
+    assert_eq!(
+        memory.lookup("수줍은 고양이 메이드", "ko", "en").as_deref(),
+        Some("A shy catgirl maid & <butler>")
+    );
+    assert_eq!(memory.lookup("수줍은 고양이 메이드", "ko", "de"), None);
+
+    let tmx = TranslationMemory::import(&memory.to_tmx()?)?;
+    assert_eq!(tmx.segments, memory.segments);
+    let xliff = TranslationMemory::import(&memory.to_xliff()?)?;
+    assert_eq!(xliff.segments, memory.segments);
+
+    // 고친 세그먼트는 남기고 오래된 기계 번역부터 지움
+    memory.record_machine("ko", "en", "메이드", "maid");
+    memory.truncate(2);
+    let languages = memory
+        .segments
+        .iter()
+        .map(|s| s.to.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(languages, ["en", "en"]);
+    assert!(memory.lookup("메이드", "ko", "en").is_some());
+    Ok(())
+}
@@ -0,0 +1,256 @@
+// 국어의 로마자 표기법(2000)을 글자 단위로 적용함. 받침 뒤 ㅇ 의 연음, ㄹㄹ, 모음 앞 ㅎ 만 처리함
+const HANGUL_START: u32 = 0xAC00;
+const HANGUL_END: u32 = 0xD7A3;
+
+const INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+const MEDIALS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+// 음절 끝 받침
+const FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+// 뒤에 모음이 올 때 넘어가는 받침 (연음). 모음 앞의 ㅎ 은 소리 나지 않음 (좋아, 많이)
+const FINALS_BEFORE_VOWEL: [&str; 28] = [
+    "", "g", "kk", "ks", "n", "nj", "n", "d", "r", "lg", "lm", "lb", "ls", "lt", "lp", "r", "m",
+    "b", "bs", "s", "ss", "ng", "j", "ch", "k", "t", "p", "",
+];
+
+// 초성 ㅇ
+const SILENT_INITIAL: usize = 11;
+// 초성 ㄹ 과 받침 ㄹ
+const RIEUL_INITIAL: usize = 5;
+const RIEUL_FINAL: usize = 8;
+
+fn decompose(c: char) -> Option<(usize, usize, usize)> {
+    let code = c as u32;
+    if !(HANGUL_START..=HANGUL_END).contains(&code) {
+        return None;
+    }
+    let index = (code - HANGUL_START) as usize;
+    Some((index / (21 * 28), index / 28 % 21, index % 28))
+}
+
+fn compose(initial: usize, medial: usize, last: usize) -> char {
+    char::from_u32(HANGUL_START + ((initial * 21 + medial) * 28 + last) as u32).unwrap()
+}
+
+// 한글을 로마자로 바꿈. 이름처럼 띄어 쓴 낱말마다 첫 글자는 대문자
+pub fn romanize(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    let mut word_start = true;
+
+    for (i, c) in chars.iter().enumerate() {
+        let Some((initial, medial, last)) = decompose(*c) else {
+            result.push(*c);
+            word_start = !c.is_alphanumeric();
+            continue;
+        };
+        let next = chars.get(i + 1).and_then(|c| decompose(*c));
+        let mut syllable = String::new();
+        // 받침이 연음되어 넘어온 경우에는 초성을 다시 쓰지 않음
+        let previous_linked = i > 0
+            && initial == SILENT_INITIAL
+            && decompose(chars[i - 1]).is_some_and(|(_, _, last)| last != 0 && last != 21);
+        // ㄹㄹ 은 ll (발로 Ballo)
+        let after_rieul = i > 0
+            && initial == RIEUL_INITIAL
+            && decompose(chars[i - 1]).is_some_and(|(_, _, last)| last == RIEUL_FINAL);
+        if after_rieul {
+            syllable.push('l');
+        } else if !previous_linked {
+            syllable.push_str(INITIALS[initial]);
+        }
+        syllable.push_str(MEDIALS[medial]);
+        match next {
+            Some((SILENT_INITIAL, _, _)) if last != 21 => {
+                syllable.push_str(FINALS_BEFORE_VOWEL[last])
+            }
+            _ => syllable.push_str(FINALS[last]),
+        }
+
+        if word_start {
+            let mut letters = syllable.chars();
+            if let Some(first) = letters.next() {
+                result.extend(first.to_uppercase());
+                result.extend(letters);
+            }
+        } else {
+            result.push_str(&syllable);
+        }
+        word_start = false;
+    }
+    result
+}
+
+// 영어 철자 -> (초성, 중성) 대응
+const LATIN_CONSONANTS: &[(&str, usize)] = &[
+    ("ch", 14),
+    ("sh", 9),
+    ("th", 9),
+    ("ph", 17),
+    ("b", 7),
+    ("c", 15),
+    ("d", 3),
+    ("f", 17),
+    ("g", 0),
+    ("h", 18),
+    ("j", 12),
+    ("k", 15),
+    ("l", 5),
+    ("m", 6),
+    ("n", 2),
+    ("p", 17),
+    ("q", 15),
+    ("r", 5),
+    ("s", 9),
+    ("t", 16),
+    ("v", 7),
+    ("x", 9),
+    ("z", 12),
+];
+const LATIN_VOWELS: &[(&str, usize)] = &[
+    ("ya", 2),
+    ("yeo", 6),
+    ("ye", 7),
+    ("yo", 12),
+    ("yu", 17),
+    ("wa", 9),
+    ("we", 15),
+    ("wi", 16),
+    ("wo", 14),
+    ("ee", 20),
+    ("oo", 13),
+    ("eo", 4),
+    ("ae", 1),
+    ("a", 0),
+    ("e", 5),
+    ("i", 20),
+    ("o", 8),
+    ("u", 13),
+    ("y", 20),
+];
+// 자음 뒤에 모음이 없을 때 받침으로 쓸 수 있는 것
+const LATIN_FINALS: &[(&str, usize)] = &[
+    ("ng", 21),
+    ("n", 4),
+    ("m", 16),
+    ("l", 8),
+    ("k", 1),
+    ("p", 17),
+    ("t", 19),
+];
+// 모음 없는 자음은 ㅡ 를 붙여 한 음절로
+const EU: usize = 18;
+
+fn take<'a>(rest: &'a str, table: &[(&str, usize)]) -> Option<(usize, &'a str)> {
+    table
+        .iter()
+        .find(|(latin, _)| rest.starts_with(latin))
+        .map(|(latin, jamo)| (*jamo, &rest[latin.len()..]))
+}
+
+// e, i, y 앞의 c 는 ㅅ 로
+fn consonant(rest: &str) -> Option<(usize, &str)> {
+    match rest.as_bytes() {
+        [b'c', b'e' | b'i' | b'y', ..] => Some((9, &rest[1..])),
+        _ => take(rest, LATIN_CONSONANTS),
+    }
+}
+
+fn hangulize_word(word: &str) -> String {
+    let word = word.to_lowercase();
+    let mut rest = word.as_str();
+    let mut result = String::new();
+
+    while !rest.is_empty() {
+        if let Some((medial, after)) = take(rest, LATIN_VOWELS) {
+            rest = after;
+            let last = final_consonant(&mut rest);
+            result.push(compose(SILENT_INITIAL, medial, last));
+        } else if let Some((initial, after)) = consonant(rest) {
+            // 모음 사이의 l 은 앞 음절에 ㄹ 받침을 더함 (Alice 알리스, Lily 릴리)
+            if rest.starts_with('l') && take(after, LATIN_VOWELS).is_some() {
+                add_rieul_final(&mut result);
+            }
+            rest = after;
+            match take(rest, LATIN_VOWELS) {
+                // 낱말 끝의 e 는 소리 나지 않음
+                Some((_, "")) if rest == "e" && !result.is_empty() => {
+                    rest = "";
+                    result.push(compose(initial, EU, 0));
+                }
+                Some((medial, after)) => {
+                    rest = after;
+                    let last = final_consonant(&mut rest);
+                    result.push(compose(initial, medial, last));
+                }
+                None => result.push(compose(initial, EU, 0)),
+            }
+        } else {
+            // w, 숫자 등은 건너뜀
+            let mut chars = rest.chars();
+            chars.next();
+            rest = chars.as_str();
+        }
+    }
+    result
+}
+
+fn add_rieul_final(result: &mut String) {
+    let Some((initial, medial, 0)) = result.chars().last().and_then(decompose) else {
+        return;
+    };
+    result.pop();
+    result.push(compose(initial, medial, RIEUL_FINAL));
+}
+
+// 모음 뒤 자음이 다음 모음에 붙지 않으면 받침으로
+fn final_consonant(rest: &mut &str) -> usize {
+    match take(rest, LATIN_FINALS) {
+        Some((last, after)) if take(after, LATIN_VOWELS).is_none() => {
+            *rest = after;
+            last
+        }
+        _ => 0,
+    }
+}
+
+// 영어 이름을 소리 나는 대로 어림한 한글로 바꿈
+pub fn hangulize(text: &str) -> String {
+    let mut result = String::new();
+    let mut word = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphabetic() {
+            word.push(c);
+        } else {
+            result.push_str(&hangulize_word(&word));
+            word.clear();
+            result.push(c);
+        }
+    }
+    result.push_str(&hangulize_word(&word));
+    result
+}
+
+#[test]
+fn test_romanize() {
+    assert_eq!(romanize("서연"), "Seoyeon");
+    assert_eq!(romanize("한국어"), "Hangugeo");
+    assert_eq!(romanize("김 민아"), "Gim Mina");
+    assert_eq!(romanize("유즈 (메이드)"), "Yujeu (Meideu)");
+    assert_eq!(romanize("Yuzu"), "Yuzu");
+    assert_eq!(romanize("발로"), "Ballo");
+    assert_eq!(romanize("좋아 많이"), "Joa Mani");
+
+    assert_eq!(hangulize("Yuzu"), "유주");
+    assert_eq!(hangulize("Mina Kim"), "미나 킴");
+    assert_eq!(hangulize("Alice"), "알리스");
+    assert_eq!(hangulize("Lily"), "릴리");
+}
@@ -8,11 +8,58 @@ pub struct Chunk {
     chunk_data: Vec<u8>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
 pub struct Character {
     pub name: String,
     pub personality: String,
     pub description: String,
+    pub scenario: String,
+    pub first_mes: String,
+    pub mes_example: String,
+    // V2 카드는 "data" 안에 모든 항목이 들어 있음
+    pub data: Option<CharacterData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct CharacterData {
+    pub name: String,
+    pub personality: String,
+    pub description: String,
+    pub scenario: String,
+    pub first_mes: String,
+    pub mes_example: String,
+    pub creator_notes: String,
+    pub alternate_greetings: Vec<String>,
+    pub character_book: Option<CharacterBook>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct CharacterBook {
+    pub entries: Vec<LorebookEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct LorebookEntry {
+    pub keys: Vec<String>,
+    pub content: String,
+}
+
+// 번역·출력에 쓰는 카드 항목들
+#[derive(Debug, Default, Clone)]
+pub struct CardFields {
+    pub name: String,
+    pub personality: String,
+    pub description: String,
+    pub scenario: String,
+    pub first_message: String,
+    pub example_dialogue: String,
+    pub creator_notes: String,
+    pub alternate_greetings: Vec<String>,
+    pub lorebook: Vec<LorebookEntry>,
 }
 
 pub fn read_chunks(data: &[u8]) -> Result<Vec<Chunk>, Error> {
@@ -64,6 +111,7 @@ pub fn read_chunks(data: &[u8]) -> Result<Vec<Chunk>, Error> {
 }
 
 // 청크가 IHDR로 시작하고 IEND로 끝나는지 확인
+#[allow(clippy::ptr_arg)]
 pub fn check_vaild(vec_chunks: &Vec<Chunk>) -> Result<(), Error> {
     if vec_chunks[0].chunk_type != "IHDR" {
         return Err(anyhow!("missing IHDR header"));
@@ -103,10 +151,48 @@ pub fn parsing_text(vec_chunks: Vec<Chunk>) -> Option<String> {
     .next()
 } 
 
-pub fn parsing_text_for_cat(text: Character) -> (String, String, String) {
-    let Character { name, description, personality } = text;
-    let description = description.replace(r#"\r\n"#, "\n");
-    (name,  personality, description)
+// V2 항목이 있으면 V2 를, 없으면 V1 항목을 씀
+pub fn parsing_fields(text: Character) -> CardFields {
+    let data = text.data.unwrap_or_default();
+    let pick = |v2: String, v1: String| {
+        let value = if v2.is_empty() { v1 } else { v2 };
+        value.replace(r#"\r\n"#, "\n")
+    };
+    CardFields {
+        name: pick(data.name, text.name),
+        personality: pick(data.personality, text.personality),
+        description: pick(data.description, text.description),
+        scenario: pick(data.scenario, text.scenario),
+        first_message: pick(data.first_mes, text.first_mes),
+        example_dialogue: pick(data.mes_example, text.mes_example),
+        creator_notes: data.creator_notes,
+        alternate_greetings: data.alternate_greetings,
+        lorebook: data
+            .character_book
+            .map(|book| book.entries)
+            .unwrap_or_default(),
+    }
+}
+
+#[test]
+fn test_parsing_fields() -> Result<(), Error> {
+    let v1: Character = serde_json::from_str(
+        r#"{"name": "Yuzu", "personality": "shy", "description": "A\\r\\nB", "first_mes": "Hi"}"#,
+    )?;
+    let fields = parsing_fields(v1);
+    assert_eq!(fields.description, "A\nB");
+    assert_eq!(fields.first_message, "Hi");
+
+    let v2: Character = serde_json::from_str(
+        r#"{"name": "Yuzu", "data": {"name": "Yuzu", "first_mes": "Hello",
+            "alternate_greetings": ["Hey"],
+            "character_book": {"entries": [{"keys": ["cat"], "content": "Cats"}]}}}"#,
+    )?;
+    let fields = parsing_fields(v2);
+    assert_eq!(fields.first_message, "Hello");
+    assert_eq!(fields.alternate_greetings, vec!["Hey"]);
+    assert_eq!(fields.lorebook[0].keys, vec!["cat"]);
+    Ok(())
 }
 
 #[test]
@@ -1 +1,5 @@
-pub const PROXY: &str = "http://0.0.0.0:9080/";
\ No newline at end of file
+// 설정에 프록시 주소가 없을 때 쓰는 기본값
+pub const DEFAULT_PROXY: &str = "http://127.0.0.1:9080/";
+
+// 프록시가 스스로 거절한 응답에 붙는 헤더. 원래 서버의 응답과 구분할 때 씀
+pub const PROXY_ERROR_HEADER: &str = "x-proxy-error";
@@ -1,16 +1,51 @@
 use anyhow::{anyhow, Error};
 use eframe::egui;
-use g_translator_m::pasring_and_translate;
+use g_translator_m::{
+    detect_language, hangulize, join_chunks, romanize, split_chunks, Backend, Detection, Glossary,
+    TranslationMemory, Translator, CHUNK_LIMIT, LANGUAGES,
+};
+use serde::{Deserialize, Serialize};
+use png_parser::CardFields;
+use albums::UploadMessage;
+use review::ParagraphFlags;
+use shared_constants::DEFAULT_PROXY;
+use std::collections::BTreeMap;
+use uploader::{content_hash, LedgerEntry, LinkStatus, Progress, UploadLedger, UploadTarget};
+use wiki_gen::{Card, Folding, Format, Localized, LorebookEntry};
 
 #[cfg(target_arch = "wasm32")]
 use std::cell::RefCell;
 #[cfg(target_arch = "wasm32")]
 use std::rc::Rc;
 
+mod albums;
+mod backends;
+mod fields;
+mod glossary;
+mod health;
+mod import;
+mod ledger;
+mod memory;
+mod review;
+mod template;
+mod uploads;
+
 const PADDING_NARROW: f32 = 3.0;
 const PADDING_WIDE: f32 = 10.0;
 const WIDTH_RATIO: f32 = 0.5;
 
+// 위키 표에 쓰이는 언어별 설명 항목 이름
+const DESCRIPTION_LABELS: &[(&str, &str)] = &[
+    ("ko", "한글 설명"),
+    ("en", "English Description"),
+    ("ja", "日本語の説明"),
+    ("zh", "中文描述"),
+    ("es", "Descripción"),
+    ("fr", "Description"),
+    ("de", "Beschreibung"),
+    ("ru", "Описание"),
+];
+
 #[derive(Debug)]
 pub struct BigFrame {
     items: Vec<String>,
@@ -22,29 +57,252 @@ pub struct BigFrame {
     #[cfg(not(target_arch = "wasm32"))]
     runtime: tokio::runtime::Runtime,
     etc_value: EtcValue,
+    settings: Settings,
     receiver: Receiver,
 }
 
 #[derive(Debug)]
 struct Receiver {
-    translation_rx: Option<std::sync::mpsc::Receiver<Vec<String>>>,
-    download_link_rx: Option<std::sync::mpsc::Receiver<String>>,
+    translation_rx: Option<std::sync::mpsc::Receiver<TranslationMessage>>,
+    download_link_rx: Option<std::sync::mpsc::Receiver<UploadMessage>>,
+    album_rx: Option<std::sync::mpsc::Receiver<UploadMessage>>,
+    health_rx: Option<std::sync::mpsc::Receiver<(String, LinkStatus)>>,
     #[cfg(target_arch = "wasm32")]
     file_rx: Option<std::sync::mpsc::Receiver<(Vec<u8>, String)>>,
 }
 
 #[derive(Default, Debug)]
 struct CharacterItem {
-    is_korean: bool,
+    source_language: String,
+    detection: Option<Detection>,
     file_name: String,
     creator: String,
     character_name: String,
     tags: String,
     download_link: String,
     note: String,
-    korean_description: String,
-    english_description: String,
+    // 언어 코드별 이름, 비고, 설명 (원본 포함)
+    names: BTreeMap<String, String>,
+    notes: BTreeMap<String, String>,
+    descriptions: BTreeMap<String, String>,
+    scenario: BTreeMap<String, String>,
+    first_message: BTreeMap<String, String>,
+    example_dialogue: BTreeMap<String, String>,
+    creator_notes: BTreeMap<String, String>,
+    alternate_greetings: Vec<BTreeMap<String, String>>,
+    // (키워드, 언어별 내용)
+    lorebook: Vec<(String, BTreeMap<String, String>)>,
+    // 번역 대신 로마자/한글 변환으로 채운 이름의 언어
+    generated_names: Vec<String>,
+    // 언어별 설명 문단 표시 (검토 창)
+    paragraph_flags: BTreeMap<String, Vec<ParagraphFlags>>,
+    // 언어별로 번역기가 마지막으로 낸 설명. 직접 고친 문단을 찾을 때 비교함
+    machine_descriptions: BTreeMap<String, String>,
     category: String,
+    // 가져온 문서에서 읽지 못한 표의 줄과 표 밖의 글. 다시 만들 때 그대로 넣음
+    extra_rows: Vec<String>,
+    extra_sections: Vec<String>,
+}
+
+// 번역 결과가 들어갈 자리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldSlot {
+    Name,
+    Note,
+    Description,
+    Scenario,
+    FirstMessage,
+    ExampleDialogue,
+    CreatorNotes,
+    AlternateGreeting(usize),
+    Lorebook(usize),
+    // 검토 창에서 다시 번역한 설명 문단 하나
+    Paragraph(usize),
+}
+
+impl FieldSlot {
+    fn label(self) -> String {
+        match self {
+            FieldSlot::Name => CardField::Name.label().to_string(),
+            FieldSlot::Note => CardField::Note.label().to_string(),
+            FieldSlot::Description => CardField::Description.label().to_string(),
+            FieldSlot::Scenario => CardField::Scenario.label().to_string(),
+            FieldSlot::FirstMessage => CardField::FirstMessage.label().to_string(),
+            FieldSlot::ExampleDialogue => CardField::ExampleDialogue.label().to_string(),
+            FieldSlot::CreatorNotes => CardField::CreatorNotes.label().to_string(),
+            FieldSlot::AlternateGreeting(i) => {
+                format!("{} {}", CardField::AlternateGreetings.label(), i + 1)
+            }
+            FieldSlot::Lorebook(i) => format!("{} {}", CardField::Lorebook.label(), i + 1),
+            FieldSlot::Paragraph(i) => {
+                format!("{} {}번째 문단", CardField::Description.label(), i + 1)
+            }
+        }
+    }
+}
+
+// 번역 작업이 GUI 로 보내는 메시지
+#[derive(Debug, Clone, PartialEq)]
+enum TranslationMessage {
+    // 조각 하나가 끝남
+    Progress,
+    Translated {
+        field: FieldSlot,
+        from: String,
+        to: String,
+        text: String,
+        // 사전 대체 번역이 섞였으면 번역 메모리에 남기지 않음
+        fallback: bool,
+    },
+    Failed {
+        field: FieldSlot,
+        from: String,
+        to: String,
+        error: String,
+    },
+}
+
+impl CharacterItem {
+    // 번역 결과가 들어갈 수 있는 모든 자리 (문단 단위 제외)
+    fn slots(&self) -> Vec<FieldSlot> {
+        let mut slots = vec![
+            FieldSlot::Name,
+            FieldSlot::Note,
+            FieldSlot::Description,
+            FieldSlot::Scenario,
+            FieldSlot::FirstMessage,
+            FieldSlot::ExampleDialogue,
+            FieldSlot::CreatorNotes,
+        ];
+        slots.extend((0..self.alternate_greetings.len()).map(FieldSlot::AlternateGreeting));
+        slots.extend((0..self.lorebook.len()).map(FieldSlot::Lorebook));
+        slots
+    }
+
+    // 해당 자리의 언어별 텍스트
+    fn texts_mut(&mut self, field: FieldSlot) -> Option<&mut BTreeMap<String, String>> {
+        match field {
+            FieldSlot::Name => Some(&mut self.names),
+            FieldSlot::Note => Some(&mut self.notes),
+            FieldSlot::Description => Some(&mut self.descriptions),
+            FieldSlot::Scenario => Some(&mut self.scenario),
+            FieldSlot::FirstMessage => Some(&mut self.first_message),
+            FieldSlot::ExampleDialogue => Some(&mut self.example_dialogue),
+            FieldSlot::CreatorNotes => Some(&mut self.creator_notes),
+            FieldSlot::AlternateGreeting(i) => self.alternate_greetings.get_mut(i),
+            FieldSlot::Lorebook(i) => self.lorebook.get_mut(i).map(|(_, content)| content),
+            FieldSlot::Paragraph(_) => None,
+        }
+    }
+
+    // 이름·비고 칸에서 직접 고친 "한글 / English" 를 언어별 글로 다시 나눔
+    fn split_joined(&mut self) {
+        split_by_language(&self.character_name, &mut self.names);
+        split_by_language(&self.note, &mut self.notes);
+    }
+
+    // 번역 결과를 해당 자리에 합치고, 이름·비고는 "한글 / English" 로 다시 묶음
+    fn apply_translation(&mut self, field: FieldSlot, to: &str, text: &str) {
+        match field {
+            FieldSlot::Paragraph(i) => self.apply_paragraph(i, to, text),
+            FieldSlot::Description => {
+                let merged = self.merge_reviewed(to, text);
+                self.descriptions.insert(to.to_string(), merged);
+                self.machine_descriptions
+                    .insert(to.to_string(), text.to_string());
+            }
+            field => {
+                if let Some(texts) = self.texts_mut(field) {
+                    texts.insert(to.to_string(), text.to_string());
+                }
+            }
+        }
+        match field {
+            FieldSlot::Name => {
+                self.generated_names.retain(|l| l != to);
+                self.character_name = join_by_language(&self.names);
+            }
+            FieldSlot::Note => self.note = join_by_language(&self.notes),
+            FieldSlot::Description
+            | FieldSlot::Scenario
+            | FieldSlot::FirstMessage
+            | FieldSlot::ExampleDialogue
+            | FieldSlot::CreatorNotes
+            | FieldSlot::AlternateGreeting(_)
+            | FieldSlot::Lorebook(_)
+            | FieldSlot::Paragraph(_) => (),
+        }
+    }
+}
+
+// 번역할 수 있는 카드 항목
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+enum CardField {
+    Name,
+    Note,
+    Description,
+    Scenario,
+    FirstMessage,
+    ExampleDialogue,
+    AlternateGreetings,
+    CreatorNotes,
+    Lorebook,
+}
+
+impl CardField {
+    const ALL: [CardField; 9] = [
+        CardField::Name,
+        CardField::Note,
+        CardField::Description,
+        CardField::Scenario,
+        CardField::FirstMessage,
+        CardField::ExampleDialogue,
+        CardField::AlternateGreetings,
+        CardField::CreatorNotes,
+        CardField::Lorebook,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            CardField::Name => "이름",
+            CardField::Note => "비고 (성격)",
+            CardField::Description => "설명",
+            CardField::Scenario => "시나리오",
+            CardField::FirstMessage => "첫 메시지",
+            CardField::ExampleDialogue => "예시 대화",
+            CardField::AlternateGreetings => "대체 인사말",
+            CardField::CreatorNotes => "제작자 노트",
+            CardField::Lorebook => "로어북",
+        }
+    }
+
+    // 선택된 항목을 (번역 결과 자리, 원문) 목록으로
+    fn pieces(self, fields: &CardFields) -> Vec<(FieldSlot, String)> {
+        let single = |field: FieldSlot, text: &String| vec![(field, text.clone())];
+        match self {
+            CardField::Name => single(FieldSlot::Name, &fields.name),
+            CardField::Note => single(FieldSlot::Note, &fields.personality),
+            CardField::Description => single(FieldSlot::Description, &fields.description),
+            CardField::Scenario => single(FieldSlot::Scenario, &fields.scenario),
+            CardField::FirstMessage => single(FieldSlot::FirstMessage, &fields.first_message),
+            CardField::ExampleDialogue => {
+                single(FieldSlot::ExampleDialogue, &fields.example_dialogue)
+            }
+            CardField::CreatorNotes => single(FieldSlot::CreatorNotes, &fields.creator_notes),
+            CardField::AlternateGreetings => fields
+                .alternate_greetings
+                .iter()
+                .enumerate()
+                .map(|(i, greeting)| (FieldSlot::AlternateGreeting(i), greeting.clone()))
+                .collect(),
+            CardField::Lorebook => fields
+                .lorebook
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| (FieldSlot::Lorebook(i), entry.content.clone()))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -53,11 +311,145 @@ struct EtcValue {
     auto_download_link: bool,
     making_translation: bool,
     making_download_link: bool,
+    show_glossary: bool,
+    show_field_selection: bool,
+    show_memory: bool,
+    show_backends: bool,
+    show_review: bool,
+    show_uploads: bool,
+    upload_job: Option<futures::future::AbortHandle>,
+    upload_progress: Progress,
+    upload_error: Option<String>,
+    // 실패한 업로드의 (내용, 파일명)
+    failed_upload: Option<(Vec<u8>, String)>,
+    // 업로드 기록에서 찾아 다시 쓴 링크의 기록
+    reused_upload: Option<LedgerEntry>,
+    show_ledger: bool,
+    ledger_filter: String,
+    show_health: bool,
+    show_template: bool,
+    // true 면 붙여 넣은 표의 링크를, false 면 업로드 기록의 링크를 점검
+    health_from_table: bool,
+    health_table: String,
+    // (링크, 점검 결과). 결과가 None 이면 아직 점검 중
+    health_results: Vec<(String, Option<LinkStatus>)>,
+    health_error: Option<String>,
+    // 다시 올리는 중인 죽은 링크
+    reuploading: Option<String>,
+    // 기록에 없는 링크를 다시 올리기 전에 묻는 (링크, 불러온 카드 파일명)
+    reupload_confirm: Option<(String, String)>,
+    // (죽은 링크, 새 링크)
+    health_replacements: Vec<(String, String)>,
+    show_albums: bool,
+    show_import: bool,
+    import_text: String,
+    import_status: Option<String>,
+    album_title: String,
+    album_description: String,
+    // 앨범 주소나 short 코드
+    album: String,
+    selected_uploads: std::collections::BTreeSet<String>,
+    // 선택한 파일을 지우기 전에 확인을 받는 중
+    confirm_delete: bool,
+    // 이번 세션에 계정으로 올린 파일
+    session_uploads: Vec<String>,
+    album_status: Vec<String>,
+    review_language: String,
+    glossary_text: String,
+    // 용어집 가져오기·내보내기에서 난 오류
+    glossary_error: Option<String>,
+    memory_text: String,
+    // None 이면 자동 감지
+    source_language: Option<String>,
+    translation_errors: Vec<String>,
+    translation_job: Option<futures::future::AbortHandle>,
+    // (끝난 조각 수, 전체 조각 수)
+    translation_progress: (usize, usize),
+}
+
+// 세션이 바뀌어도 유지되는 설정
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(default)]
+struct Settings {
+    glossary: Glossary,
+    memory: TranslationMemory,
+    backends: Vec<Backend>,
+    target_languages: Vec<String>,
+    translate_fields: Vec<CardField>,
+    upload_target: UploadTarget,
+    // 비어 있으면 catbox 에 익명으로 올림
+    catbox_userhash: String,
+    // 계정으로 올린 파일 링크
+    uploaded_files: Vec<String>,
+    // (제목, 주소)
+    albums: Vec<(String, String)>,
+    upload_ledger: UploadLedger,
+    output_format: Format,
+    // 형식별로 고친 틀. 없으면 기본 틀
+    output_templates: BTreeMap<Format, String>,
+    // 나무위키 출력에서 긴 값 접기
+    folding: Folding,
+    // 웹 빌드는 CORS 때문에 번역·업로드 요청을 프록시로 보내야 함
+    use_proxy: bool,
+    proxy: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            glossary: Glossary::default(),
+            memory: TranslationMemory::default(),
+            backends: Translator::default().backends,
+            target_languages: vec!["ko".to_string(), "en".to_string()],
+            translate_fields: vec![CardField::Name, CardField::Note, CardField::Description],
+            upload_target: UploadTarget::default(),
+            catbox_userhash: String::new(),
+            uploaded_files: vec![],
+            albums: vec![],
+            upload_ledger: UploadLedger::default(),
+            output_format: Format::default(),
+            output_templates: BTreeMap::new(),
+            folding: Folding::default(),
+            use_proxy: cfg!(target_arch = "wasm32"),
+            proxy: DEFAULT_PROXY.to_string(),
+        }
+    }
+}
+
+impl Settings {
+    fn proxy(&self) -> Option<String> {
+        let proxy = self.proxy.trim();
+        (self.use_proxy && !proxy.is_empty()).then(|| proxy.to_string())
+    }
+
+    // 웹 빌드에서 ?proxy=주소 로 프록시를 바꾸거나, ?proxy= 로 끔
+    #[cfg(target_arch = "wasm32")]
+    fn apply_query(&mut self) {
+        let search = web_sys::window().and_then(|window| window.location().search().ok());
+        let params = search.and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok());
+        if let Some(proxy) = params.and_then(|params| params.get("proxy")) {
+            self.use_proxy = !proxy.is_empty();
+            if !proxy.is_empty() {
+                self.proxy = proxy;
+            }
+        }
+    }
 }
 
 impl BigFrame {
     pub fn _new(cc: &eframe::CreationContext<'_>) -> Self {
         _setup_custom_font(&cc.egui_ctx);
+        #[allow(unused_mut)]
+        let mut settings: Settings = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, eframe::APP_KEY))
+            .unwrap_or_default();
+        #[cfg(target_arch = "wasm32")]
+        settings.apply_query();
+        Self::with_settings(settings)
+    }
+
+    fn with_settings(settings: Settings) -> Self {
         let strings = [
             "파일명/Image: Yuzu".to_string(),
             "제작자/Creator: 제작자".to_string(),
@@ -65,8 +457,6 @@ impl BigFrame {
             "태그/Tags: [[고양이수인(Catgirl)]], [[여성(female)]], [[메이드(maid)]]".to_string(),
             "Download link: https://catbox.moe".to_string(),
             "비고/Note: 수줍은 고양이 소녀 메이드 / Shy Cat Girl Maid".to_string(),
-            "한글 설명".to_string(),
-            "English description".to_string(),
             "분류: [[분류:소녀(Girl)]] [[분류:메이드(Maid)]] [[분류:고양이수인(Catgirl)]]"
                 .to_string(),
         ];
@@ -90,9 +480,12 @@ impl BigFrame {
             #[cfg(not(target_arch = "wasm32"))]
             runtime,
             etc_value,
+            settings,
             receiver: Receiver {
                 translation_rx: None,
                 download_link_rx: None,
+                album_rx: None,
+                health_rx: None,
                 #[cfg(target_arch = "wasm32")]
                 file_rx: None,
             },
@@ -100,19 +493,72 @@ impl BigFrame {
     }
 
     fn clear_fields(&mut self) {
+        self.cancel_upload();
+        self.etc_value.upload_error = None;
+        self.etc_value.failed_upload = None;
+        self.etc_value.reused_upload = None;
+        self.etc_value.reuploading = None;
+        self.etc_value.reupload_confirm = None;
+        self.character_item.download_link = String::new();
         self.character_item.file_name = String::new();
         self.character_item.creator = String::new();
-        self.character_item.character_name = String::new();
         self.character_item.tags = String::new();
-        self.character_item.download_link = String::new();
-        self.character_item.note = String::new();
-        self.character_item.korean_description = String::new();
-        self.character_item.english_description = String::new();
         self.character_item.category = String::new();
+        self.character_item.extra_rows.clear();
+        self.character_item.extra_sections.clear();
+        self.clear_texts();
+    }
+
+    // 직접 적은 항목과 업로드는 그대로 두고 카드에서 읽은 글과 번역만 지움
+    fn clear_texts(&mut self) {
+        self.cancel_translation();
+        self.record_post_edits();
+        self.character_item.character_name = String::new();
+        self.character_item.note = String::new();
+        self.character_item.names.clear();
+        self.character_item.notes.clear();
+        self.character_item.descriptions.clear();
+        self.character_item.scenario.clear();
+        self.character_item.first_message.clear();
+        self.character_item.example_dialogue.clear();
+        self.character_item.creator_notes.clear();
+        self.character_item.alternate_greetings.clear();
+        self.character_item.lorebook.clear();
+        self.character_item.paragraph_flags.clear();
+        self.character_item.machine_descriptions.clear();
+        self.character_item.generated_names.clear();
+        self.character_item.detection = None;
+        self.etc_value.translation_errors.clear();
+    }
+
+    // 진행 중인 번역을 멈추고 남은 결과는 버림
+    fn cancel_translation(&mut self) {
+        if let Some(job) = self.etc_value.translation_job.take() {
+            job.abort();
+        }
+        self.receiver.translation_rx.take();
+        self.etc_value.making_translation = false;
     }
 
-    fn parsing_png(&mut self) -> Result<[String; 4], Error> {
-        use png_parser::{check_vaild, parsing_text, parsing_text_for_cat, read_chunks, Character};
+    // 사람이 고친 번역문을 번역 메모리에 남김
+    fn record_post_edits(&mut self) {
+        self.character_item.split_joined();
+        let from = self.character_item.source_language.clone();
+        for field in self.character_item.slots() {
+            let Some(texts) = self.character_item.texts_mut(field) else {
+                continue;
+            };
+            let Some(source) = texts.get(&from) else {
+                continue;
+            };
+            for (to, text) in texts.iter().filter(|(to, _)| **to != from) {
+                self.settings.memory.record_edit(&from, to, source, text);
+            }
+        }
+    }
+
+    fn parsing_png(&mut self) -> Result<CardFields, Error> {
+        use png_parser::{check_vaild, parsing_fields, parsing_text, read_chunks, Character};
 
         #[cfg(not(target_arch = "wasm32"))]
         let file_data = read_file_to_vec(self.file_path.as_ref().unwrap())?;
@@ -137,94 +583,129 @@ impl BigFrame {
         }
 
         let character: Character = serde_json::from_str(script.unwrap().as_str())?;
-        let (character_name, note, description) = parsing_text_for_cat(character);
-        let (mut korean_description, mut english_description) = (String::new(), String::new());
-        if character_name.chars().all(|c| c.is_ascii()) {
-            self.character_item.is_korean = false;
-            english_description = description;
-        } else {
-            self.character_item.is_korean = true;
-            korean_description = description;
-        }
-        Ok([
-            character_name,
-            note,
-            korean_description,
-            english_description,
-        ])
+        let fields = parsing_fields(character);
+        let detection = detect_language(&format!("{}\n{}", fields.name, fields.description));
+        self.character_item.source_language = match &self.etc_value.source_language {
+            Some(language) => language.clone(),
+            None => detection.as_ref().map_or("en", |d| d.language).to_string(),
+        };
+        self.character_item.detection = detection;
+
+        Ok(fields)
     }
 
     // Binding parsed data to variables
-    fn binding(&mut self) -> Result<[String; 4], Error> {
-        // let [a, b, c, d] = self.parsing_png();
-        let [a, b, c, d] = match self.parsing_png() {
-            Ok(strings) => strings,
-            Err(e) => return Err(e),
-        };
-        self.character_item.character_name = a.clone();
-        self.character_item.note = b.clone();
-        self.character_item.korean_description = c.clone();
-        self.character_item.english_description = d.clone();
+    fn binding(&mut self) -> Result<CardFields, Error> {
+        let fields = self.parsing_png()?;
+        let language = self.character_item.source_language.clone();
+        let item = &mut self.character_item;
+        item.character_name = fields.name.clone();
+        item.note = fields.personality.clone();
+        item.alternate_greetings = vec![BTreeMap::new(); fields.alternate_greetings.len()];
+        item.lorebook = fields
+            .lorebook
+            .iter()
+            .map(|entry| (entry.keys.join(", "), BTreeMap::new()))
+            .collect();
+        for field in CardField::ALL {
+            for (slot, text) in field.pieces(&fields) {
+                if let Some(texts) = item.texts_mut(slot) {
+                    texts.insert(language.clone(), text);
+                }
+            }
+        }
+
+        Ok(fields)
+    }
 
-        Ok([a, b, c, d])
+    // 원본 언어, 번역 대상 언어, 이미 있는 설명의 언어를 LANGUAGES 순서로
+    fn description_languages(&self) -> Vec<String> {
+        let mut languages = vec![];
+        if !self.character_item.source_language.is_empty() {
+            languages.push(self.character_item.source_language.clone());
+        }
+        languages.extend(self.settings.target_languages.iter().cloned());
+        languages.extend(self.character_item.descriptions.keys().cloned());
+        languages.sort_by_key(|language| (language_order(language), language.clone()));
+        languages.dedup();
+        languages
     }
 
     fn updating_translated_data(&mut self) {
         if let Some(data_rx) = &self.receiver.translation_rx {
             match data_rx.try_recv() {
-                Ok(translated) => match translated[0].as_str() {
-                    x if x == "name" && !self.character_item.is_korean => {
-                        self.character_item.character_name =
-                            format!("{} / {}", translated[1].clone(), translated[2].clone());
+                Ok(TranslationMessage::Progress) => self.etc_value.translation_progress.0 += 1,
+                Ok(TranslationMessage::Failed {
+                    field,
+                    from,
+                    to,
+                    error,
+                }) => self.etc_value.translation_errors.push(format!(
+                    "[{} → {}] {}: {}",
+                    from,
+                    to,
+                    field.label(),
+                    error
+                )),
+                Ok(TranslationMessage::Translated {
+                    field,
+                    from,
+                    to,
+                    text,
+                    fallback,
+                }) => {
+                    let item = &mut self.character_item;
+                    let source = item.texts_mut(field).and_then(|texts| texts.get(&from));
+                    if let Some(source) = source.filter(|_| !fallback) {
+                        self.settings.memory.record_machine(&from, &to, source, &text);
                     }
-                    x if x == "name" && self.character_item.is_korean => {
-                        self.character_item.character_name =
-                            format!("{} / {}", translated[2].clone(), translated[1].clone());
-                    }
-                    x if x == "note" && !self.character_item.is_korean => {
-                        self.character_item.note =
-                            format!("{} / {}", translated[1].clone(), translated[2].clone())
-                    }
-                    x if x == "note" && !self.character_item.is_korean => {
-                        self.character_item.note =
-                            format!("{} / {}", translated[2].clone(), translated[1].clone())
-                    }
-                    x if x == "desc" && self.character_item.is_korean => {
-                        self.character_item.english_description = translated[1].clone()
-                    }
-                    x if x == "desc" && !self.character_item.is_korean => {
-                        self.character_item.korean_description = translated[1].clone()
-                    }
-                    _ => (),
-                },
+                    item.apply_translation(field, &to, &text);
+                }
                 Err(error) => match error {
                     std::sync::mpsc::TryRecvError::Empty => {
                         // eprintln!("Error. Translation channel is empty.")
                     }
                     std::sync::mpsc::TryRecvError::Disconnected => {
                         self.receiver.translation_rx.take();
+                        self.etc_value.translation_job.take();
                         self.etc_value.making_translation = false;
+                        self.fill_missing_names();
                     }
                 },
             }
         }
     }
 
+    // 번역이 꺼져 있거나 실패해 "한글 / English" 의 한쪽 이름이 비어 있으면 로마자 표기로 채움
+    fn fill_missing_names(&mut self) {
+        let item = &mut self.character_item;
+        let Some(source) = item.names.get(&item.source_language).cloned() else {
+            return;
+        };
+        let (language, name) = match item.source_language.as_str() {
+            "ko" => ("en", romanize(&source)),
+            "en" => ("ko", hangulize(&source)),
+            _ => return,
+        };
+        let missing = item.names.get(language).is_none_or(|name| name.trim().is_empty());
+        if !missing || !self.settings.target_languages.iter().any(|l| l == language) {
+            return;
+        }
+        item.names.insert(language.to_string(), name);
+        item.generated_names.push(language.to_string());
+        item.character_name = join_by_language(&item.names);
+    }
+
     fn updating_download_link(&mut self) {
-        if let Some(link_rx) = &self.receiver.download_link_rx {
-            match link_rx.try_recv() {
-                Ok(link) => {
-                    self.character_item.download_link = link;
-                }
-                Err(error) => match error {
-                    std::sync::mpsc::TryRecvError::Empty => {
-                        // eprintln!("Error. Download link channel is empty.")
-                    }
-                    std::sync::mpsc::TryRecvError::Disconnected => {
-                        self.receiver.download_link_rx.take();
-                        self.etc_value.making_download_link = false;
-                    }
-                },
+        let Some(link_rx) = &self.receiver.download_link_rx else {
+            return;
+        };
+        match link_rx.try_recv() {
+            Ok(message) => self.handle_upload_message(message),
+            Err(std::sync::mpsc::TryRecvError::Empty) => (),
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.receiver.download_link_rx.take();
+                self.etc_value.making_download_link = false;
             }
         }
     }
@@ -254,111 +735,181 @@ impl BigFrame {
         }
     }
 
-    fn all_processing(&mut self) -> Result<(), Error> {
-        let mut vecs = vec![];
-        match self.binding() {
-            Ok(strings) => strings.into_iter().for_each(|a| vecs.push(a)),
-            Err(e) => {
-                return Err(e);
-            }
-        }
+    // (번역 결과 자리, 원문) 목록을 대상 언어마다 번역하는 작업을 시작함
+    fn start_translation(&mut self, pieces: Vec<(FieldSlot, String)>, targets: Vec<String>) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.etc_value.making_translation = true;
+        self.receiver.translation_rx = Some(rx);
+        let translator = Translator {
+            glossary: self.settings.glossary.clone(),
+            memory: self.settings.memory.clone(),
+            backends: self.settings.backends.clone(),
+            proxy: self.settings.proxy(),
+        };
+        let total = pieces
+            .iter()
+            .map(|(_, text)| split_chunks(text, CHUNK_LIMIT).len())
+            .sum::<usize>();
+        self.etc_value.translation_progress = (0, total * targets.len());
+
+        let from = self.character_item.source_language.clone();
+        // 진행 중인 요청과 재시도 대기까지 한꺼번에 멈출 수 있도록 통째로 취소함
+        let (job, handle) =
+            futures::future::abortable(translate_all(tx, translator, pieces, from, targets));
+        self.etc_value.translation_job = Some(handle);
+        let job = async move {
+            let _ = job.await;
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        self.runtime.spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(job);
+    }
 
-        let english_d = vecs.pop().unwrap();
-        let korean_d = vecs.pop().unwrap();
-        let chara_note = vecs.pop().unwrap();
-        let chara_name = vecs.pop().unwrap();
+    // 불러온 카드의 (내용, 파일명)
+    fn card_file(&self) -> Result<(Vec<u8>, String), Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let file_path = self
+                .file_path
+                .as_ref()
+                .ok_or_else(|| anyhow!("No file is loaded"))?;
+            let file_name = file_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            Ok((read_file_to_vec(file_path)?, file_name))
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.file_content
+                .borrow()
+                .clone()
+                .ok_or_else(|| anyhow!("No file is loaded"))
+        }
+    }
 
-        if self.etc_value.auto_translation {
-            let (tx, rx) = std::sync::mpsc::channel();
-            self.etc_value.making_translation = true;
-            let from = if self.character_item.is_korean {
-                "ko"
-            } else {
-                "en"
-            };
-            let to = if self.character_item.is_korean {
-                "en"
-            } else {
-                "ko"
+    // 메모리에 있는 카드를 설정한 곳에 올리고 링크를 받음
+    fn start_upload(&mut self, data: Vec<u8>, file_name: String) {
+        self.cancel_upload();
+        self.etc_value.upload_error = None;
+        self.etc_value.failed_upload = None;
+        self.etc_value.reused_upload = None;
+        let hash = content_hash(&data);
+        let target = self.settings.upload_target.clone();
+        let (download_tx, download_rx) = std::sync::mpsc::channel();
+        self.etc_value.making_download_link = true;
+        self.receiver.download_link_rx = Some(download_rx);
+        let proxy = self.settings.proxy();
+        let uploader = self
+            .settings
+            .upload_target
+            .uploader(proxy.as_deref(), &self.settings.catbox_userhash);
+        let progress = Progress::new();
+        self.etc_value.upload_progress = progress.clone();
+
+        let (job, handle) = futures::future::abortable(async move {
+            // 실패하면 다시 시도할 수 있게 카드를 돌려보냄
+            let message = match uploader.upload(data.clone(), &file_name, &progress).await {
+                Ok(link) => UploadMessage::Uploaded {
+                    entry: LedgerEntry::new(&link, &target, &file_name),
+                    hash,
+                },
+                Err(e) => UploadMessage::UploadFailed {
+                    error: format!("업로드 실패: {e}"),
+                    data,
+                    file_name,
+                },
             };
-            self.receiver.translation_rx = Some(rx);
-            let is_korean = self.character_item.is_korean;
-
-            #[cfg(not(target_arch = "wasm32"))]
-            self.runtime.spawn(async move {
-                let t_name = translate_name(tx.clone(), chara_name, from, to);
-                let t_note = translate_note(tx.clone(), chara_note, from, to);
-                let t_d = if !is_korean {
-                    translate_d(tx, english_d, from, to)
-                } else {
-                    translate_d(tx, korean_d, from, to)
-                };
-
-                tokio::join!(t_name, t_note, t_d);
-            });
+            if let Err(e) = download_tx.send(message) {
+                eprintln!("Failed to send the download link to receiver...{e}");
+            }
+        });
+        self.etc_value.upload_job = Some(handle);
+        let job = async move {
+            let _ = job.await;
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        self.runtime.spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(job);
+    }
 
-            #[cfg(target_arch = "wasm32")]
-            wasm_bindgen_futures::spawn_local(async move {
-                let t_name = translate_name(tx.clone(), chara_name, from, to);
-                let t_note = translate_note(tx.clone(), chara_note, from, to);
-                let t_d = if !is_korean {
-                    translate_d(tx, english_d, from, to)
-                } else {
-                    translate_d(tx, korean_d, from, to)
-                };
-
-                futures::join!(t_name, t_note, t_d);
-            });
+    fn cancel_upload(&mut self) {
+        if let Some(job) = self.etc_value.upload_job.take() {
+            job.abort();
         }
+        self.receiver.download_link_rx.take();
+        self.etc_value.making_download_link = false;
+        self.etc_value.reuploading = None;
+    }
 
-        if self.etc_value.auto_download_link {
-            let (download_tx, download_rx) = std::sync::mpsc::channel();
-            self.etc_value.making_download_link = true;
-            self.receiver.download_link_rx = Some(download_rx);
-
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                let file_path = self.file_path.clone().unwrap();
-                let file_path = file_path.to_str().unwrap().to_owned();
-
-                self.runtime.spawn(async move {
-                    if let Ok(link) = catbox::file::from_file(file_path.as_str(), None)
-                        .await
-                        .map_err(|_| anyhow!("Failed to create the download link"))
-                    {
-                        // std::thread::sleep(std::time::Duration::from_secs(2));
-                        // let link = "text.com".to_string();
-                        if let Err(e) = download_tx.send(link) {
-                            eprintln!("Failed to send the download link to receiver...{e}");
-                        }
-                        println!("Creating download link complete");
-                    }
-                });
+    // 실패한 업로드는 그때의 카드로, 취소한 업로드는 지금 카드로 다시 올림
+    fn retry_upload(&mut self) {
+        let file = match self.etc_value.failed_upload.take() {
+            Some(file) => Ok(file),
+            None => self.card_file(),
+        };
+        match file {
+            Ok((data, file_name)) => {
+                // 다시 올리던 링크는 다시 시도해도 바꿀 대상으로 남김
+                let reuploading = self.etc_value.reuploading.take();
+                self.start_upload(data, file_name);
+                self.etc_value.reuploading = reuploading;
             }
+            Err(e) => self.etc_value.upload_error = Some(e.to_string()),
+        }
+    }
 
-            #[cfg(target_arch = "wasm32")]
-            {
-                let a = self.file_content.borrow();
-                let file = a.as_ref().unwrap().clone();
+    fn all_processing(&mut self) -> Result<(), Error> {
+        self.translate_card()?;
 
-                wasm_bindgen_futures::spawn_local(async move {
-                    if let Ok(link) = catbox_wasm::upload_file(file.0, file.1)
-                        .await
-                        .map_err(|_| anyhow!("Failed to create the download link"))
-                    {
-                        // let link = "text.com".to_string();
-                        if let Err(e) = download_tx.send(link) {
-                            eprintln!("Failed to send the download link to receiver...{e}");
-                        }
-                        println!("Creating download link complete");
-                    }
-                });
+        if self.etc_value.auto_download_link {
+            let (data, file_name) = self.card_file()?;
+            // 같은 카드를 같은 곳에 올린 적이 있으면 그 링크를 씀
+            let host = self.settings.upload_target.destination();
+            let ledger = &self.settings.upload_ledger;
+            match ledger.lookup(&content_hash(&data), &host).cloned() {
+                Some(entry) => {
+                    self.character_item.download_link = entry.url.clone();
+                    self.etc_value.reused_upload = Some(entry);
+                }
+                None => self.start_upload(data, file_name),
             }
         }
 
         Ok(())
     }
 
+    // 카드를 읽어 원본 언어를 정하고 번역을 시작함
+    fn translate_card(&mut self) -> Result<(), Error> {
+        let fields = self.binding()?;
+        let pieces = self
+            .settings
+            .translate_fields
+            .iter()
+            .flat_map(|field| field.pieces(&fields))
+            .filter(|(_, text)| !text.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        let from = self.character_item.source_language.clone();
+        let targets = self
+            .settings
+            .target_languages
+            .iter()
+            .filter(|to| **to != from)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if self.etc_value.auto_translation && !targets.is_empty() && !pieces.is_empty() {
+            self.start_translation(pieces, targets);
+        }
+        if !self.etc_value.making_translation {
+            self.fill_missing_names();
+        }
+        Ok(())
+    }
+
     fn render_leftside(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         ui.horizontal(|ui| {
             ui.vertical(|ui| {
@@ -400,13 +951,148 @@ impl BigFrame {
                         });
                     }
                 }
+                if let Some(detection) = &self.character_item.detection {
+                    ui.label(format!(
+                        "감지된 언어: {} ({:.0}%)",
+                        detection.language,
+                        detection.confidence * 100.0
+                    ));
+                }
+                if self.etc_value.making_translation {
+                    let (done, total) = self.etc_value.translation_progress;
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::ProgressBar::new(done as f32 / total.max(1) as f32)
+                                .desired_width(200.0)
+                                .show_percentage(),
+                        );
+                        if ui.button("번역 취소").clicked() {
+                            self.cancel_translation();
+                            // 받지 못한 이름은 끝났을 때처럼 표기 변환으로 채움
+                            self.fill_missing_names();
+                        }
+                    });
+                }
+                for error in &self.etc_value.translation_errors {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                if self.etc_value.making_download_link {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::ProgressBar::new(self.etc_value.upload_progress.fraction())
+                                .desired_width(200.0)
+                                .show_percentage(),
+                        );
+                        if ui.button("업로드 취소").clicked() {
+                            self.cancel_upload();
+                        }
+                    });
+                }
+                if let Some(entry) = &self.etc_value.reused_upload {
+                    let text = format!(
+                        "{} 에 올렸던 링크를 다시 씁니다.",
+                        entry.uploaded_at.format("%Y-%m-%d")
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(text);
+                        if ui.button("새로 올리기").clicked() {
+                            self.retry_upload();
+                        }
+                    });
+                }
+                if let Some(error) = self.etc_value.upload_error.clone() {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(egui::Color32::RED, error);
+                        if ui.button("다시 시도").clicked() {
+                            self.retry_upload();
+                        }
+                    });
+                }
             });
             ui.with_layout(egui::Layout::top_down_justified(egui::Align::RIGHT), |ui| {
-                ui.checkbox(&mut self.etc_value.auto_translation, "구글 번역 사용");
+                if ui.button("용어집...").clicked() {
+                    self.etc_value.show_glossary = !self.etc_value.show_glossary;
+                }
+                if ui.button("출력 틀...").clicked() {
+                    self.etc_value.show_template = !self.etc_value.show_template;
+                }
+                if ui.button("나무위키 가져오기...").clicked() {
+                    self.etc_value.show_import = !self.etc_value.show_import;
+                }
+                if ui.button("설명 검토...").clicked() {
+                    self.etc_value.show_review = !self.etc_value.show_review;
+                }
+                if ui.button("번역 엔진...").clicked() {
+                    self.etc_value.show_backends = !self.etc_value.show_backends;
+                }
+                if ui.button("번역 메모리...").clicked() {
+                    self.etc_value.show_memory = !self.etc_value.show_memory;
+                }
+                if ui.button("번역할 항목...").clicked() {
+                    self.etc_value.show_field_selection = !self.etc_value.show_field_selection;
+                }
+                if ui.button("업로드 설정...").clicked() {
+                    self.etc_value.show_uploads = !self.etc_value.show_uploads;
+                }
+                if ui.button("올린 파일...").clicked() {
+                    self.etc_value.show_albums = !self.etc_value.show_albums;
+                }
+                if ui.button("업로드 기록...").clicked() {
+                    self.etc_value.show_ledger = !self.etc_value.show_ledger;
+                }
+                if ui.button("링크 점검...").clicked() {
+                    self.etc_value.show_health = !self.etc_value.show_health;
+                }
+                ui.checkbox(&mut self.etc_value.auto_translation, "자동 번역 사용");
+                let mut source_changed = false;
+                egui::ComboBox::from_label("원본 언어")
+                    .selected_text(
+                        self.etc_value
+                            .source_language
+                            .as_deref()
+                            .unwrap_or("자동 감지"),
+                    )
+                    .show_ui(ui, |ui| {
+                        let language = &mut self.etc_value.source_language;
+                        source_changed |= ui.selectable_value(language, None, "자동 감지").changed();
+                        for (code, name) in LANGUAGES {
+                            source_changed |= ui
+                                .selectable_value(language, Some(code.to_string()), *name)
+                                .changed();
+                        }
+                    });
+                // 불러온 카드가 있으면 바꾼 원본 언어로 다시 읽고 번역함
+                if source_changed && self.card_file().is_ok() {
+                    self.clear_texts();
+                    if let Err(error) = self.translate_card() {
+                        eprintln!("{error}");
+                    }
+                }
+                ui.menu_button("번역 대상 언어", |ui| {
+                    for (code, name) in LANGUAGES {
+                        let target_languages = &mut self.settings.target_languages;
+                        let mut checked = target_languages.iter().any(|l| l == code);
+                        if ui.checkbox(&mut checked, *name).changed() {
+                            if checked {
+                                target_languages.push(code.to_string());
+                            } else {
+                                target_languages.retain(|l| l != code);
+                            }
+                        }
+                    }
+                });
                 ui.checkbox(
                     &mut self.etc_value.auto_download_link,
                     "다운로드 링크 자동 생성",
                 );
+                ui.checkbox(&mut self.settings.use_proxy, "CORS 프록시 사용");
+                if self.settings.use_proxy {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.settings.proxy)
+                            .hint_text(DEFAULT_PROXY)
+                            .desired_width(200.0),
+                    );
+                }
             });
         });
 
@@ -466,7 +1152,11 @@ impl BigFrame {
 
         #[cfg(not(target_arch = "wasm32"))]
         {
-            if self.receiver.translation_rx.is_some() || self.receiver.download_link_rx.is_some() {
+            if self.receiver.translation_rx.is_some()
+                || self.receiver.download_link_rx.is_some()
+                || self.receiver.album_rx.is_some()
+                || self.receiver.health_rx.is_some()
+            {
                 ctx.request_repaint();
             };
         }
@@ -475,6 +1165,8 @@ impl BigFrame {
         {
             if self.receiver.translation_rx.is_some()
                 || self.receiver.download_link_rx.is_some()
+                || self.receiver.album_rx.is_some()
+                || self.receiver.health_rx.is_some()
                 || self.receiver.file_rx.is_some()
             {
                 ctx.request_repaint();
@@ -483,9 +1175,13 @@ impl BigFrame {
 
         self.updating_translated_data();
         self.updating_download_link();
+        self.updating_albums();
+        self.updating_health();
         #[cfg(target_arch = "wasm32")]
         self.updating_file();
 
+        let languages = self.description_languages();
+        let generated_names = self.character_item.generated_names.join(", ");
         let mut name_arr = [
             &mut self.character_item.file_name,
             &mut self.character_item.creator,
@@ -493,8 +1189,6 @@ impl BigFrame {
             &mut self.character_item.tags,
             &mut self.character_item.download_link,
             &mut self.character_item.note,
-            &mut self.character_item.korean_description,
-            &mut self.character_item.english_description,
             &mut self.character_item.category,
         ];
         let mut items_iter = self.items.iter();
@@ -523,31 +1217,54 @@ impl BigFrame {
                         .desired_width(screen_width),
                 );
             }
+            if i == 2 && !generated_names.is_empty() {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    format!("자동 생성된 이름 ({}): 로마자/한글 표기 변환", generated_names),
+                );
+            }
             ui.add_space(PADDING_WIDE);
         });
 
-        items_iter.by_ref().take(2).enumerate().for_each(|(i, a)| {
+        // 이름 칸들이 character_item 을 빌리고 있어 고친 언어는 끝에서 표시함
+        let mut edited_language = None;
+        languages.iter().for_each(|language| {
+            // 글이 없는 언어에 빈 설명을 만들지 않도록 고쳤을 때만 넣음
+            let mut description = self
+                .character_item
+                .descriptions
+                .get(language)
+                .cloned()
+                .unwrap_or_default();
             egui::containers::Resize::default()
                 .fixed_size([screen_width * WIDTH_RATIO, 100.0])
                 .show(ui, |ui| {
-                    ui.label(egui::RichText::new(a).text_style(egui::TextStyle::Heading));
+                    ui.label(
+                        egui::RichText::new(description_label(language))
+                            .text_style(egui::TextStyle::Heading),
+                    );
                     ui.add_space(PADDING_NARROW);
                     egui::ScrollArea::vertical()
-                        .id_source(format!("{}", i).as_str())
+                        .id_source(language.as_str())
                         .show(ui, |ui| {
                             if self.etc_value.making_translation {
-                                let a = &mut name_arr[i + 6].as_str();
+                                let a = &mut description.as_str();
                                 let text_edit = egui::TextEdit::multiline(a)
                                     .margin(egui::vec2(10., 10.))
                                     .desired_rows(4)
                                     .desired_width(screen_width);
                                 ui.add(text_edit);
                             } else {
-                                let text_edit = egui::TextEdit::multiline(name_arr[i + 6])
+                                let text_edit = egui::TextEdit::multiline(&mut description)
                                     .margin(egui::vec2(10., 10.))
                                     .desired_rows(4)
                                     .desired_width(screen_width);
-                                ui.add(text_edit);
+                                if ui.add(text_edit).changed() {
+                                    self.character_item
+                                        .descriptions
+                                        .insert(language.clone(), description.clone());
+                                    edited_language = Some(language.clone());
+                                }
                             }
                         });
                     ui.add_space(PADDING_WIDE);
@@ -566,30 +1283,75 @@ impl BigFrame {
                     .desired_width(screen_width),
             );
         });
+
+        if let Some(language) = edited_language {
+            self.character_item.mark_edited(&language);
+        }
+
+        ui.add_space(PADDING_WIDE);
+        self.render_extra_fields(ui, screen_width);
     }
 
-    fn render_central(&self, ctx: &egui::Context) {
-        let result = format!(
-            "\
-||<width=15%>이미지||<width=50%>[[파일:{}.png|align=center]]||
-||<width=15%>제작자 / Creator||<width=85%>{}||
-||<width=15%>이름 / Name||<width=85%>{}||
-||<width=15%>태그 / Tags||<width=85%>{}||
-||<width=15%>Download link||<width=85%>[[{}]]||
-||<width=15%>비고 / Note||<width=85%>{}||
-||<width=15%>한글 설명||<width=85%>{}||
-||<width=15%>English Description||<width=85%>{}||
-{}",
-            self.character_item.file_name,
-            self.character_item.creator,
-            self.character_item.character_name,
-            self.character_item.tags,
-            self.character_item.download_link,
-            self.character_item.note,
-            self.character_item.korean_description,
-            self.character_item.english_description,
-            self.character_item.category
-        );
+    // 틀에 넘길 카드. 언어별 글은 표에 나올 순서로
+    fn card_context(&self) -> Card {
+        let item = &self.character_item;
+        let descriptions = self
+            .description_languages()
+            .into_iter()
+            .map(|language| Localized {
+                label: description_label(&language),
+                text: item.descriptions.get(&language).cloned().unwrap_or_default(),
+                language,
+            })
+            .filter(|description| !description.text.trim().is_empty())
+            .collect();
+        Card {
+            file_name: item.file_name.clone(),
+            creator: item.creator.clone(),
+            name: item.character_name.clone(),
+            names: localized(&item.names),
+            generated_names: item.generated_names.clone(),
+            tags: item.tags.clone(),
+            download_link: item.download_link.clone(),
+            note: item.note.clone(),
+            notes: localized(&item.notes),
+            descriptions,
+            scenario: localized(&item.scenario),
+            first_message: localized(&item.first_message),
+            example_dialogue: localized(&item.example_dialogue),
+            creator_notes: localized(&item.creator_notes),
+            alternate_greetings: item.alternate_greetings.iter().map(localized).collect(),
+            lorebook: item
+                .lorebook
+                .iter()
+                .map(|(keys, content)| LorebookEntry {
+                    keys: keys.clone(),
+                    content: localized(content),
+                })
+                .collect(),
+            category: item.category.clone(),
+            extra_rows: item.extra_rows.clone(),
+            extra_sections: item.extra_sections.clone(),
+            folding: self.settings.folding.clone(),
+        }
+    }
+
+    fn output_template(&self) -> &str {
+        let format = self.settings.output_format;
+        self.settings
+            .output_templates
+            .get(&format)
+            .map_or(format.default_template(), String::as_str)
+    }
+
+    fn render_central(&mut self, ctx: &egui::Context) {
+        let format = self.settings.output_format;
+        let card = self.card_context();
+        let (result, error) = match wiki_gen::render(format, self.output_template(), &card) {
+            Ok(result) => (result, None),
+            Err(e) => (String::new(), Some(e.to_string())),
+        };
+        let mut copied = false;
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::vertical().show(ui, |ui| {
                 if ui
@@ -599,24 +1361,36 @@ impl BigFrame {
                     )
                     .clicked()
                 {
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                        ui.output_mut(|o| o.copied_text = result.clone());
-                    }
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                        let window = web_sys::window().unwrap();
-                        let clipboard: web_sys::Clipboard = window.navigator().clipboard().unwrap();
-                        clipboard.write_text(&result);
-                    }
+                    copied = true;
+                    copy_to_clipboard(ui, &result);
+                }
+                ui.add_space(PADDING_WIDE);
+                egui::ComboBox::from_label("출력 형식")
+                    .selected_text(self.settings.output_format.label())
+                    .show_ui(ui, |ui| {
+                        for format in Format::ALL {
+                            ui.selectable_value(
+                                &mut self.settings.output_format,
+                                format,
+                                format.label(),
+                            );
+                        }
+                    });
+                ui.add_space(PADDING_WIDE);
+                if let Some(error) = &error {
+                    ui.colored_label(egui::Color32::RED, format!("틀 오류: {error}"));
                 }
-                ui.add_space(PADDING_WIDE * 2.0);
                 ui.add(
                     egui::TextEdit::multiline(&mut result.as_str())
                         .desired_width(ctx.available_rect().width()),
                 )
             });
         });
+
+        // 복사한 결과를 최종본으로 보고 고친 번역을 기록
+        if copied {
+            self.record_post_edits();
+        }
     }
 }
 
@@ -635,6 +1409,22 @@ impl eframe::App for BigFrame {
             });
 
         self.render_central(ctx);
+        self.render_glossary_window(ctx);
+        self.render_field_selection_window(ctx);
+        self.render_memory_window(ctx);
+        self.render_backends_window(ctx);
+        self.render_review_window(ctx);
+        self.render_uploads_window(ctx);
+        self.render_albums_window(ctx);
+        self.render_ledger_window(ctx);
+        self.render_health_window(ctx);
+        self.render_template_window(ctx);
+        self.render_import_window(ctx);
+    }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        self.record_post_edits();
+        eframe::set_value(storage, eframe::APP_KEY, &self.settings);
     }
 }
 
@@ -718,96 +1508,247 @@ fn _config_text_styles(ctx: &egui::Context) {
     ctx.set_style(style);
 }
 
-async fn translate_name(
-    tx: std::sync::mpsc::Sender<Vec<String>>,
+// 선택된 항목을 한 작업으로, 대상 언어마다 차례로 번역
+async fn translate_all(
+    tx: std::sync::mpsc::Sender<TranslationMessage>,
+    translator: Translator,
+    pieces: Vec<(FieldSlot, String)>,
+    from: String,
+    targets: Vec<String>,
+) {
+    for to in targets {
+        let jobs = pieces.iter().map(|(field, text)| {
+            translate_field(tx.clone(), &translator, *field, text.clone(), &from, &to)
+        });
+        futures::future::join_all(jobs).await;
+    }
+}
+
+async fn translate_field(
+    tx: std::sync::mpsc::Sender<TranslationMessage>,
+    translator: &Translator,
+    field: FieldSlot,
     input: String,
     from: &str,
     to: &str,
 ) {
-    let input_original = input.clone();
-    if let Ok(translated) = pasring_and_translate(input, from, to).await {
-        if let Err(e) = tx.send(vec!["name".to_string(), translated, input_original]) {
-            eprintln!("Error sending translated data...{e}");
+    // 통째로 번역 메모리에 있으면 나눠 보낼 필요가 없음
+    if let Some(translated) = translator.memory.lookup(&input, from, to) {
+        for _ in split_chunks(&input, CHUNK_LIMIT) {
+            send_progress(&tx);
         }
-        println!("name translation complete");
+        return send_translated(&tx, field, from, to, translated, false);
     }
+
+    // 1900글자씩 자름
+    let chunks = split_chunks(&input, CHUNK_LIMIT);
+    let chunked = chunks.len() > 1;
+    let mut translated_result = vec![];
+    let mut fallback = false;
+
+    for chunk in &chunks {
+        // 그냥 "\n" 이라고만 했더니 조금 작동이 이상해서 "\\\n"으로 구분하고 나중에 "\n"으로 일괄 바꾸기로 함
+        let chunk = if chunked {
+            chunk.text.replace('\n', "\\\n")
+        } else {
+            chunk.text.clone()
+        };
+        match translator.translate(chunk, from, to).await {
+            Ok(translated) => {
+                fallback |= translated.fallback;
+                translated_result.push(translated.text.replace("\\\n", "\n"));
+            }
+            // 중간이 빠진 번역을 보내지 않도록 여기서 그만둠
+            Err(error) => return send_translation_error(&tx, field, from, to, error),
+        }
+        send_progress(&tx);
+    }
+
+    let translated = join_chunks(&chunks, &translated_result);
+    send_translated(&tx, field, from, to, translated, fallback);
 }
 
-async fn translate_note(
-    tx: std::sync::mpsc::Sender<Vec<String>>,
-    input: String,
+fn send_translated(
+    tx: &std::sync::mpsc::Sender<TranslationMessage>,
+    field: FieldSlot,
     from: &str,
     to: &str,
+    text: String,
+    fallback: bool,
 ) {
-    let input_original = input.clone();
-    if let Ok(translated) = pasring_and_translate(input, from, to).await {
-        if let Err(e) = tx.send(vec!["note".to_string(), translated, input_original]) {
-            eprintln!("Error sending translated data...{e}");
-        }
-        println!("note translation complete");
+    let message = TranslationMessage::Translated {
+        field,
+        from: from.to_string(),
+        to: to.to_string(),
+        text,
+        fallback,
+    };
+    if let Err(e) = tx.send(message) {
+        eprintln!("Error sending translated data...{e}");
+    } else {
+        println!("{field:?} translation complete");
     }
 }
 
-async fn translate_d(
-    tx: std::sync::mpsc::Sender<Vec<String>>,
-    input: String,
+fn send_progress(tx: &std::sync::mpsc::Sender<TranslationMessage>) {
+    if let Err(e) = tx.send(TranslationMessage::Progress) {
+        eprintln!("Error sending translation progress...{e}");
+    }
+}
+
+fn send_translation_error(
+    tx: &std::sync::mpsc::Sender<TranslationMessage>,
+    field: FieldSlot,
     from: &str,
     to: &str,
+    error: Error,
 ) {
-    let input_count = input.chars().count();
-    match input_count {
-        x if x < 1900 => {
-            if let Ok(translated) = pasring_and_translate(input, from, to).await {
-                if let Err(e) = tx.send(vec!["desc".to_string(), translated]) {
-                    eprintln!("Error sending translated data...{e}");
-                }
-                println!("description translation complete");
-            }
-        }
-        x if x >= 1900 => {
-            // 1900글자씩 자름
-            let input_lines = input.lines().collect::<Vec<_>>();
-            let mut count = 0;
-            let mut vecs_line = vec![];
-            let mut translated_result = vec![];
-
-            for line in input_lines {
-                count += line.chars().count();
-                println!("{count}: {line}");
-                if count >= 1900 {
-                    // 그냥 "\n" 이라고만 했더니 조금 작동이 이상해서 "\\\n"으로 구분하고 나중에 "\n"으로 일괄 바꾸기로 함
-                    let input = vecs_line.join("\\\n");
-                    // let input_c = input.clone();
-                    if let Ok(translated) = pasring_and_translate(input, from, to).await {
-                        let translated = translated.replace("\\\n", "\n");
-                        // println!("{}", translated);
-                        // println!("{}", input_c);
-                        translated_result.push(translated);
-                        vecs_line = vec![];
-                        count = line.chars().count();
-                    }
-                }
-                vecs_line.push(line);
-            }
+    eprintln!("{error}");
+    let message = TranslationMessage::Failed {
+        field,
+        from: from.to_string(),
+        to: to.to_string(),
+        error: error.to_string(),
+    };
+    if let Err(e) = tx.send(message) {
+        eprintln!("Error sending translation error...{e}");
+    }
+}
 
-            let input = vecs_line.join("\\\n");
-            if let Ok(translated) = pasring_and_translate(input, from, to).await {
-                let translated = translated.replace("\\\n", "\n");
-                translated_result.push(translated);
-            }
+// LANGUAGES 에 없는 언어는 뒤로
+fn language_order(language: &str) -> usize {
+    LANGUAGES
+        .iter()
+        .position(|(code, _)| *code == language)
+        .unwrap_or(LANGUAGES.len())
+}
 
-            let translated = translated_result.join("\n");
+fn description_label(language: &str) -> String {
+    DESCRIPTION_LABELS
+        .iter()
+        .find(|(code, _)| *code == language)
+        .map_or_else(|| format!("{} Description", language), |(_, label)| label.to_string())
+}
 
-            if let Err(e) = tx.send(vec!["desc".to_string(), translated]) {
-                eprintln!("Error sending translated data...{e}");
-            } else {
-                println!("description translation complete");
-            }
-        }
-        _ => (),
+// "유즈 / Yuzu" 처럼 언어 순서대로 이어 붙임
+fn join_by_language(texts: &BTreeMap<String, String>) -> String {
+    let mut texts = texts.iter().collect::<Vec<_>>();
+    texts.sort_by_key(|(language, _)| language_order(language));
+    texts
+        .into_iter()
+        .map(|(_, text)| text.as_str())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+// join_by_language 를 되돌림. 나뉜 개수가 언어 수와 다르면 그대로 둠
+fn split_by_language(joined: &str, texts: &mut BTreeMap<String, String>) {
+    let mut languages = texts.keys().cloned().collect::<Vec<_>>();
+    languages.sort_by_key(|language| language_order(language));
+    let parts = joined.split(" / ").collect::<Vec<_>>();
+    if parts.len() != languages.len() {
+        return;
+    }
+    for (language, part) in languages.into_iter().zip(parts) {
+        texts.insert(language, part.trim().to_string());
+    }
+}
+
+// 언어 순서대로 (언어, 언어 이름, 글) 목록을 만듦
+fn localized(texts: &BTreeMap<String, String>) -> Vec<Localized> {
+    let mut texts = texts
+        .iter()
+        .map(|(language, text)| Localized {
+            language: language.clone(),
+            label: LANGUAGES
+                .iter()
+                .find(|(code, _)| code == language)
+                .map_or_else(|| language.clone(), |(_, name)| name.to_string()),
+            text: text.clone(),
+        })
+        .collect::<Vec<_>>();
+    texts.sort_by_key(|text| language_order(&text.language));
+    texts
+}
+
+fn copy_to_clipboard(ui: &egui::Ui, text: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        ui.output_mut(|o| o.copied_text = text.to_string());
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = ui;
+        let window = web_sys::window().unwrap();
+        let clipboard: web_sys::Clipboard = window.navigator().clipboard().unwrap();
+        let _ = clipboard.write_text(text);
     }
 }
 
 fn read_file_to_vec(path: &std::path::PathBuf) -> std::io::Result<Vec<u8>> {
     std::fs::read(path)
 }
+
+#[test]
+fn test_apply_translation() {
+    // 한국어 -> 영어
+    let mut item = CharacterItem {
+        source_language: "ko".to_string(),
+        ..Default::default()
+    };
+    item.names.insert("ko".to_string(), "유즈".to_string());
+    item.notes.insert("ko".to_string(), "수줍음".to_string());
+    item.generated_names.push("en".to_string());
+    item.apply_translation(FieldSlot::Name, "en", "Yuzu");
+    item.apply_translation(FieldSlot::Note, "en", "Shy");
+    assert_eq!(item.character_name, "유즈 / Yuzu");
+    assert_eq!(item.note, "수줍음 / Shy");
+    assert!(item.generated_names.is_empty());
+
+    // 영어 -> 한국어. 번역된 한국어 비고도 다시 써져야 함
+    let mut item = CharacterItem {
+        source_language: "en".to_string(),
+        ..Default::default()
+    };
+    item.names.insert("en".to_string(), "Yuzu".to_string());
+    item.notes.insert("en".to_string(), "Shy".to_string());
+    item.apply_translation(FieldSlot::Name, "ko", "유즈");
+    item.apply_translation(FieldSlot::Note, "ko", "수줍음");
+    assert_eq!(item.character_name, "유즈 / Yuzu");
+    assert_eq!(item.note, "수줍음 / Shy");
+    assert_eq!(item.notes.get("ko").map(String::as_str), Some("수줍음"));
+
+    // 이름 칸에서 직접 고친 것도 언어별로 나뉘어 기록됨
+    item.character_name = "유즈 / Yuzuha".to_string();
+    item.note = "수줍음".to_string();
+    item.split_joined();
+    assert_eq!(item.names["en"], "Yuzuha");
+    assert_eq!(item.notes["en"], "Shy");
+
+    // 직접 고친 문단은 전체 다시 번역에서 지키고, 문단 하나만 다시 번역할 수 있음
+    item.descriptions.insert("ko".to_string(), "첫 줄\n고친 줄".to_string());
+    item.paragraph_flags.insert(
+        "ko".to_string(),
+        vec![
+            ParagraphFlags::default(),
+            ParagraphFlags {
+                locked: false,
+                edited: true,
+            },
+        ],
+    );
+    item.apply_translation(FieldSlot::Description, "ko", "새 첫 줄\n새 둘째 줄");
+    assert_eq!(item.descriptions["ko"], "새 첫 줄\n고친 줄");
+    item.apply_translation(FieldSlot::Paragraph(1), "ko", "다시 번역한 줄");
+    assert_eq!(item.descriptions["ko"], "새 첫 줄\n다시 번역한 줄");
+    assert!(!item.paragraph_flags["ko"][1].edited);
+
+    // 본문 칸에서 고친 문단도 표시되어 전체 다시 번역에서 지킴
+    item.descriptions
+        .insert("ko".to_string(), "새 첫 줄\n본문에서 고친 줄".to_string());
+    item.mark_edited("ko");
+    assert!(!item.paragraph_flags["ko"][0].edited);
+    assert!(item.paragraph_flags["ko"][1].edited);
+    item.apply_translation(FieldSlot::Description, "ko", "또 첫 줄\n또 둘째 줄");
+    assert_eq!(item.descriptions["ko"], "또 첫 줄\n본문에서 고친 줄");
+}
@@ -0,0 +1,279 @@
+use super::{BigFrame, PADDING_NARROW, PADDING_WIDE};
+use eframe::egui;
+use uploader::{CatboxAccount, LedgerEntry, UploadTarget};
+
+// 업로드와 앨범 작업의 결과
+pub(super) enum UploadMessage {
+    Uploaded {
+        hash: String,
+        entry: LedgerEntry,
+    },
+    UploadFailed {
+        error: String,
+        data: Vec<u8>,
+        file_name: String,
+    },
+    AlbumCreated { title: String, link: String },
+    Done(String),
+    Deleted(Vec<String>),
+    Failed(String),
+}
+
+enum AlbumAction {
+    Create,
+    Add,
+    Remove,
+    Delete,
+}
+
+impl BigFrame {
+    pub(super) fn handle_upload_message(&mut self, message: UploadMessage) {
+        let settings = &mut self.settings;
+        let etc_value = &mut self.etc_value;
+        let mut replaced = None;
+        match message {
+            UploadMessage::Uploaded { hash, entry } => {
+                let link = entry.url.clone();
+                let account = settings.upload_target == UploadTarget::Catbox
+                    && !settings.catbox_userhash.trim().is_empty();
+                if account {
+                    settings.uploaded_files.push(link.clone());
+                    etc_value.session_uploads.push(link.clone());
+                }
+                settings.upload_ledger.record(&hash, entry);
+                if let Some(dead) = etc_value.reuploading.take() {
+                    replaced = Some((dead, link.clone()));
+                }
+                self.character_item.download_link = link;
+                println!("Creating download link complete");
+            }
+            UploadMessage::UploadFailed {
+                error,
+                data,
+                file_name,
+            } => {
+                eprintln!("{error}");
+                etc_value.upload_error = Some(error);
+                etc_value.failed_upload = Some((data, file_name));
+            }
+            UploadMessage::AlbumCreated { title, link } => {
+                etc_value
+                    .album_status
+                    .push(format!("앨범을 만들었습니다: {link}"));
+                etc_value.album = link.clone();
+                settings.albums.push((title, link));
+            }
+            UploadMessage::Done(text) => etc_value.album_status.push(text),
+            UploadMessage::Deleted(files) => {
+                settings.uploaded_files.retain(|link| !files.contains(link));
+                // 지운 링크를 같은 카드에 다시 쓰지 않도록 기록에서도 뺌
+                for link in &files {
+                    settings.upload_ledger.remove(link);
+                }
+                etc_value
+                    .session_uploads
+                    .retain(|link| !files.contains(link));
+                etc_value
+                    .selected_uploads
+                    .retain(|link| !files.contains(link));
+                etc_value
+                    .album_status
+                    .push(format!("{}개 파일을 지웠습니다.", files.len()));
+            }
+            UploadMessage::Failed(error) => {
+                eprintln!("{error}");
+                etc_value.album_status.push(error);
+            }
+        }
+        if let Some((dead, link)) = replaced {
+            self.replace_dead_link(dead, link);
+        }
+    }
+
+    pub(super) fn updating_albums(&mut self) {
+        let Some(album_rx) = &self.receiver.album_rx else {
+            return;
+        };
+        match album_rx.try_recv() {
+            Ok(message) => self.handle_upload_message(message),
+            Err(std::sync::mpsc::TryRecvError::Empty) => (),
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.receiver.album_rx.take();
+            }
+        }
+    }
+
+    fn run_album_action(&mut self, action: AlbumAction) {
+        let proxy = self.settings.proxy();
+        let account = CatboxAccount::new(&self.settings.catbox_userhash, proxy.as_deref());
+        let files = self
+            .etc_value
+            .selected_uploads
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let album = self.etc_value.album.trim().to_string();
+        let title = self.etc_value.album_title.trim().to_string();
+        let description = self.etc_value.album_description.clone();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.receiver.album_rx = Some(rx);
+        let job = async move {
+            let message = match action {
+                AlbumAction::Create => account
+                    .create_album(&title, &description, &files)
+                    .await
+                    .map(|link| UploadMessage::AlbumCreated { title, link }),
+                AlbumAction::Add => account
+                    .add_to_album(&album, &files)
+                    .await
+                    .map(UploadMessage::Done),
+                AlbumAction::Remove => account
+                    .remove_from_album(&album, &files)
+                    .await
+                    .map(UploadMessage::Done),
+                AlbumAction::Delete => account
+                    .delete_files(&files)
+                    .await
+                    .map(|_| UploadMessage::Deleted(files)),
+            };
+            let message = message.unwrap_or_else(|e| UploadMessage::Failed(e.to_string()));
+            if let Err(e) = tx.send(message) {
+                eprintln!("Failed to send the album result to receiver...{e}");
+            }
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        self.runtime.spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(job);
+    }
+
+    pub(super) fn render_albums_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_albums;
+        let mut action = None;
+
+        egui::Window::new("올린 파일 / Uploads")
+            .open(&mut open)
+            .default_width(600.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                let settings = &mut self.settings;
+                let etc_value = &mut self.etc_value;
+                if settings.catbox_userhash.trim().is_empty() {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        "업로드 설정에서 catbox userhash 를 넣어야 앨범을 만들거나 파일을 지울 수 있습니다.",
+                    );
+                }
+                let busy = self.receiver.album_rx.is_some();
+                let selected = !etc_value.selected_uploads.is_empty();
+
+                ui.horizontal(|ui| {
+                    if ui.button("이번 세션 파일 선택").clicked() {
+                        etc_value
+                            .selected_uploads
+                            .extend(etc_value.session_uploads.iter().cloned());
+                    }
+                    if ui.button("선택 해제").clicked() {
+                        etc_value.selected_uploads.clear();
+                    }
+                });
+                for link in &settings.uploaded_files {
+                    ui.horizontal(|ui| {
+                        let mut checked = etc_value.selected_uploads.contains(link);
+                        if ui.checkbox(&mut checked, "").changed() {
+                            if checked {
+                                etc_value.selected_uploads.insert(link.clone());
+                            } else {
+                                etc_value.selected_uploads.remove(link);
+                            }
+                        }
+                        ui.hyperlink(link);
+                    });
+                }
+                ui.add_space(PADDING_WIDE);
+
+                egui::Grid::new("album_grid").show(ui, |ui| {
+                    ui.label("앨범 제목");
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut etc_value.album_title);
+                        if ui.button("제작자 이름").clicked() {
+                            etc_value.album_title = self.character_item.creator.clone();
+                        }
+                    });
+                    ui.end_row();
+                    ui.label("앨범 설명");
+                    ui.text_edit_singleline(&mut etc_value.album_description);
+                    ui.end_row();
+                    ui.label("앨범");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut etc_value.album)
+                                .hint_text("https://catbox.moe/c/..."),
+                        );
+                        egui::ComboBox::from_id_source("known_albums")
+                            .selected_text("저장된 앨범")
+                            .show_ui(ui, |ui| {
+                                for (title, link) in &settings.albums {
+                                    ui.selectable_value(
+                                        &mut etc_value.album,
+                                        link.clone(),
+                                        format!("{title} ({link})"),
+                                    );
+                                }
+                            });
+                    });
+                    ui.end_row();
+                });
+                ui.add_space(PADDING_NARROW);
+
+                ui.horizontal(|ui| {
+                    let has_album = !etc_value.album.trim().is_empty();
+                    let button = egui::Button::new("선택한 파일로 앨범 만들기");
+                    if ui.add_enabled(!busy && selected, button).clicked() {
+                        action = Some(AlbumAction::Create);
+                    }
+                    let button = egui::Button::new("앨범에 추가");
+                    if ui.add_enabled(!busy && selected && has_album, button).clicked() {
+                        action = Some(AlbumAction::Add);
+                    }
+                    let button = egui::Button::new("앨범에서 빼기");
+                    if ui.add_enabled(!busy && selected && has_album, button).clicked() {
+                        action = Some(AlbumAction::Remove);
+                    }
+                    let button = egui::Button::new("선택한 파일 삭제");
+                    if ui.add_enabled(!busy && selected, button).clicked() {
+                        etc_value.confirm_delete = true;
+                    }
+                });
+                // 지운 파일은 되살릴 수 없으므로 무엇을 지우는지 보여 주고 한 번 더 받음
+                if etc_value.confirm_delete && selected {
+                    let files = &etc_value.selected_uploads;
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        format!("{}개 파일을 catbox 에서 영구히 지웁니다.", files.len()),
+                    );
+                    for link in files {
+                        ui.label(link);
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!busy, egui::Button::new("지우기")).clicked() {
+                            action = Some(AlbumAction::Delete);
+                            etc_value.confirm_delete = false;
+                        }
+                        if ui.button("취소").clicked() {
+                            etc_value.confirm_delete = false;
+                        }
+                    });
+                }
+                for status in &etc_value.album_status {
+                    ui.label(status);
+                }
+            });
+        self.etc_value.show_albums = open;
+
+        if let Some(action) = action {
+            self.run_album_action(action);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::{BigFrame, PADDING_NARROW, PADDING_WIDE};
+use eframe::egui;
+use g_translator_m::Backend;
+
+impl BigFrame {
+    pub(super) fn render_backends_window(&mut self, ctx: &egui::Context) {
+        let backends = &mut self.settings.backends;
+
+        egui::Window::new("번역 엔진 / Backends")
+            .open(&mut self.etc_value.show_backends)
+            .default_width(500.0)
+            .show(ctx, |ui| {
+                ui.label("위에서부터 차례로 시도하고, 실패하면 다음 엔진으로 넘어갑니다.");
+                ui.add_space(PADDING_NARROW);
+
+                let mut moved_up = None;
+                let mut removed = None;
+                for (i, backend) in backends.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(i > 0, egui::Button::new("▲")).clicked() {
+                            moved_up = Some(i);
+                        }
+                        ui.label(backend.name());
+                        match backend {
+                            Backend::LocalHttp { url } => {
+                                ui.add(
+                                    egui::TextEdit::singleline(url)
+                                        .hint_text("http://localhost:5000"),
+                                );
+                            }
+                            Backend::Command { program, args } => {
+                                ui.add(
+                                    egui::TextEdit::singleline(program)
+                                        .hint_text("argos-translate")
+                                        .desired_width(120.0),
+                                );
+                                ui.add(
+                                    egui::TextEdit::singleline(args)
+                                        .hint_text("--from-lang {from} --to-lang {to}"),
+                                );
+                            }
+                            Backend::Google | Backend::Dictionary => (),
+                        }
+                        if ui.small_button("삭제").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                if let Some(i) = moved_up {
+                    backends.swap(i - 1, i);
+                }
+                if let Some(i) = removed {
+                    backends.remove(i);
+                }
+
+                ui.add_space(PADDING_WIDE);
+                ui.horizontal(|ui| {
+                    ui.label("추가:");
+                    if ui.button("Google").clicked() {
+                        backends.push(Backend::Google);
+                    }
+                    if ui.button("Local HTTP").clicked() {
+                        backends.push(Backend::LocalHttp {
+                            url: "http://localhost:5000".to_string(),
+                        });
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if ui.button("Command").clicked() {
+                        backends.push(Backend::Command {
+                            program: String::new(),
+                            args: String::new(),
+                        });
+                    }
+                    if ui.button("Dictionary").clicked() {
+                        backends.push(Backend::Dictionary);
+                    }
+                });
+            });
+    }
+}
@@ -0,0 +1,93 @@
+use super::{language_order, BigFrame, CardField, PADDING_NARROW};
+use eframe::egui;
+use std::collections::BTreeMap;
+
+impl BigFrame {
+    pub(super) fn render_field_selection_window(&mut self, ctx: &egui::Context) {
+        let translate_fields = &mut self.settings.translate_fields;
+
+        egui::Window::new("번역할 항목")
+            .open(&mut self.etc_value.show_field_selection)
+            .resizable(false)
+            .show(ctx, |ui| {
+                for field in CardField::ALL {
+                    let mut checked = translate_fields.contains(&field);
+                    if ui.checkbox(&mut checked, field.label()).changed() {
+                        if checked {
+                            translate_fields.push(field);
+                        } else {
+                            translate_fields.retain(|f| *f != field);
+                        }
+                    }
+                }
+            });
+    }
+
+    // 이름, 비고, 설명 외의 카드 항목들. 내용이 있는 항목만 접어서 보여줌
+    pub(super) fn render_extra_fields(&mut self, ui: &mut egui::Ui, screen_width: f32) {
+        let read_only = self.etc_value.making_translation;
+        let item = &mut self.character_item;
+        let mut sections: Vec<(String, &mut BTreeMap<String, String>)> = vec![
+            (CardField::Scenario.label().to_string(), &mut item.scenario),
+            (
+                CardField::FirstMessage.label().to_string(),
+                &mut item.first_message,
+            ),
+            (
+                CardField::ExampleDialogue.label().to_string(),
+                &mut item.example_dialogue,
+            ),
+            (
+                CardField::CreatorNotes.label().to_string(),
+                &mut item.creator_notes,
+            ),
+        ];
+        sections.extend(
+            item.alternate_greetings
+                .iter_mut()
+                .enumerate()
+                .map(|(i, greeting)| {
+                    (
+                        format!("{} {}", CardField::AlternateGreetings.label(), i + 1),
+                        greeting,
+                    )
+                }),
+        );
+        sections.extend(item.lorebook.iter_mut().map(|(keys, content)| {
+            (
+                format!("{}: {}", CardField::Lorebook.label(), keys),
+                content,
+            )
+        }));
+
+        for (i, (label, texts)) in sections.into_iter().enumerate() {
+            if texts.values().all(|text| text.trim().is_empty()) {
+                continue;
+            }
+            egui::CollapsingHeader::new(label)
+                .id_source(("extra_field", i))
+                .show(ui, |ui| {
+                    let mut texts = texts.iter_mut().collect::<Vec<_>>();
+                    texts.sort_by_key(|(language, _)| {
+                        (language_order(language), language.to_string())
+                    });
+                    for (language, text) in texts {
+                        ui.label(language.as_str());
+                        let mut shown = text.as_str();
+                        let text_edit = if read_only {
+                            egui::TextEdit::multiline(&mut shown)
+                        } else {
+                            egui::TextEdit::multiline(text)
+                        };
+                        ui.add(
+                            text_edit
+                                .margin(egui::vec2(10., 10.))
+                                .desired_rows(3)
+                                .desired_width(screen_width),
+                        );
+                        ui.add_space(PADDING_NARROW);
+                    }
+                });
+        }
+    }
+}
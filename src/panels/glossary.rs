@@ -0,0 +1,140 @@
+use super::{BigFrame, PADDING_NARROW, PADDING_WIDE};
+use eframe::egui;
+use g_translator_m::{Glossary, GlossaryEntry};
+
+impl BigFrame {
+    pub(super) fn render_glossary_window(&mut self, ctx: &egui::Context) {
+        let glossary = &mut self.settings.glossary;
+        let glossary_text = &mut self.etc_value.glossary_text;
+        let glossary_error = &mut self.etc_value.glossary_error;
+
+        egui::Window::new("용어집 / Glossary")
+            .open(&mut self.etc_value.show_glossary)
+            .default_width(600.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                ui.label("번역 전에 원문 용어를 지정한 번역어로 고정합니다.");
+                ui.add_space(PADDING_NARROW);
+
+                let mut removed = None;
+                egui::Grid::new("glossary_grid")
+                    .striped(true)
+                    .num_columns(5)
+                    .show(ui, |ui| {
+                        ui.label("원본 언어");
+                        ui.label("대상 언어");
+                        ui.label("원문");
+                        ui.label("번역어");
+                        ui.end_row();
+
+                        for (i, entry) in glossary.entries.iter_mut().enumerate() {
+                            ui.add(egui::TextEdit::singleline(&mut entry.from).desired_width(40.0));
+                            ui.add(egui::TextEdit::singleline(&mut entry.to).desired_width(40.0));
+                            ui.add(egui::TextEdit::singleline(&mut entry.source));
+                            ui.add(egui::TextEdit::singleline(&mut entry.target));
+                            if ui.small_button("삭제").clicked() {
+                                removed = Some(i);
+                            }
+                            ui.end_row();
+                        }
+                    });
+                if let Some(i) = removed {
+                    glossary.entries.remove(i);
+                }
+
+                if ui.button("항목 추가").clicked() {
+                    glossary.entries.push(GlossaryEntry {
+                        from: "ko".to_string(),
+                        to: "en".to_string(),
+                        source: String::new(),
+                        target: String::new(),
+                    });
+                }
+
+                ui.add_space(PADDING_WIDE);
+                ui.separator();
+                ui.label("CSV/TSV 가져오기·내보내기 (헤더: from, to, source, target)");
+                ui.add_space(PADDING_NARROW);
+
+                ui.horizontal(|ui| {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        if ui.button("파일 가져오기...").clicked() {
+                            if let Some(path) = rfd::FileDialog::new()
+                                .add_filter("glossary", &["csv", "tsv", "txt"])
+                                .pick_file()
+                            {
+                                let delimiter = delimiter_for(&path);
+                                let imported = std::fs::read_to_string(&path)
+                                    .map_err(anyhow::Error::from)
+                                    .and_then(|data| Glossary::from_delimited(&data, delimiter));
+                                *glossary_error = merge(glossary, imported);
+                            }
+                        }
+                        if ui.button("파일로 내보내기...").clicked() {
+                            if let Some(path) = rfd::FileDialog::new()
+                                .add_filter("glossary", &["csv", "tsv"])
+                                .set_file_name("glossary.tsv")
+                                .save_file()
+                            {
+                                let delimiter = delimiter_for(&path);
+                                *glossary_error = glossary
+                                    .to_delimited(delimiter)
+                                    .and_then(|data| Ok(std::fs::write(&path, data)?))
+                                    .err()
+                                    .map(|error| format!("내보내지 못했습니다: {error}"));
+                            }
+                        }
+                    }
+
+                    if ui.button("붙여넣은 TSV 가져오기").clicked() {
+                        let imported = Glossary::from_delimited(glossary_text, b'\t');
+                        *glossary_error = merge(glossary, imported);
+                    }
+                    if ui.button("CSV 가져오기").clicked() {
+                        let imported = Glossary::from_delimited(glossary_text, b',');
+                        *glossary_error = merge(glossary, imported);
+                    }
+                    if ui.button("TSV로 내보내기").clicked() {
+                        match glossary.to_delimited(b'\t') {
+                            Ok(data) => {
+                                *glossary_text = data;
+                                *glossary_error = None;
+                            }
+                            Err(error) => {
+                                *glossary_error = Some(format!("내보내지 못했습니다: {error}"))
+                            }
+                        }
+                    }
+                });
+                if let Some(error) = glossary_error {
+                    ui.colored_label(egui::Color32::RED, error.as_str());
+                }
+                ui.add_space(PADDING_NARROW);
+                ui.add(
+                    egui::TextEdit::multiline(glossary_text)
+                        .desired_rows(6)
+                        .desired_width(f32::INFINITY),
+                );
+            });
+    }
+}
+
+// 가져온 용어집을 합치고, 실패했으면 보여 줄 오류를 돌려줌
+fn merge(glossary: &mut Glossary, imported: Result<Glossary, anyhow::Error>) -> Option<String> {
+    match imported {
+        Ok(imported) => {
+            glossary.merge(imported);
+            None
+        }
+        Err(error) => Some(format!("가져오지 못했습니다: {error}")),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn delimiter_for(path: &std::path::Path) -> u8 {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("csv") => b',',
+        _ => b'\t',
+    }
+}
@@ -0,0 +1,277 @@
+use super::{copy_to_clipboard, BigFrame, PADDING_NARROW, PADDING_WIDE};
+use eframe::egui;
+use uploader::{check_health, content_hash, extract_links, LinkStatus};
+
+impl BigFrame {
+    fn start_health_check(&mut self) {
+        let links = if self.etc_value.health_from_table {
+            extract_links(&self.etc_value.health_table)
+        } else {
+            self.settings
+                .upload_ledger
+                .iter()
+                .into_iter()
+                .map(|(_, entry)| entry.url.clone())
+                .filter(|url| url.starts_with("http"))
+                .collect()
+        };
+        self.etc_value.health_results = links.iter().map(|url| (url.clone(), None)).collect();
+        self.etc_value.health_error = None;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.receiver.health_rx = Some(rx);
+        let proxy = self.settings.proxy();
+        let job = async move {
+            for url in links {
+                let status = check_health(&url, proxy.as_deref()).await;
+                if tx.send((url, status)).is_err() {
+                    break;
+                }
+            }
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        self.runtime.spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(job);
+    }
+
+    pub(super) fn updating_health(&mut self) {
+        let Some(health_rx) = &self.receiver.health_rx else {
+            return;
+        };
+        loop {
+            match health_rx.try_recv() {
+                Ok((url, status)) => {
+                    let results = &mut self.etc_value.health_results;
+                    if let Some(result) = results.iter_mut().find(|(link, _)| *link == url) {
+                        result.1 = Some(status);
+                    }
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.receiver.health_rx.take();
+                    break;
+                }
+            }
+        }
+    }
+
+    // 불러온 카드가 죽은 링크의 카드와 같으면 다시 올림.
+    // 기록에 없는 링크는 같은 카드인지 알 수 없으므로 confirmed 일 때만 올림
+    fn reupload_dead_link(&mut self, url: &str, confirmed: bool) {
+        let (data, file_name) = match self.card_file() {
+            Ok(file) => file,
+            Err(_) => {
+                let error = "다시 올릴 카드를 먼저 불러오세요.".to_string();
+                self.etc_value.health_error = Some(error);
+                return;
+            }
+        };
+        match self.settings.upload_ledger.find_url(url) {
+            Some((hash, entry)) if hash != content_hash(&data) => {
+                let error = format!(
+                    "불러온 카드가 이 링크의 카드({})와 다릅니다.",
+                    entry.file_name
+                );
+                self.etc_value.health_error = Some(error);
+                return;
+            }
+            Some(_) => (),
+            None if !confirmed => {
+                self.etc_value.reupload_confirm = Some((url.to_string(), file_name));
+                return;
+            }
+            None => (),
+        }
+        self.etc_value.health_error = None;
+        self.etc_value.reupload_confirm = None;
+        // start_upload 가 이전 업로드를 취소하며 비우므로 그 뒤에 넣음
+        self.start_upload(data, file_name);
+        self.etc_value.reuploading = Some(url.to_string());
+    }
+
+    // 다시 올린 링크로 점검 결과와 붙여 넣은 표를 고침
+    pub(super) fn replace_dead_link(&mut self, dead: String, link: String) {
+        self.settings.upload_ledger.remove(&dead);
+        let etc_value = &mut self.etc_value;
+        for result in etc_value.health_results.iter_mut() {
+            if result.0 == dead {
+                *result = (link.clone(), Some(LinkStatus::Alive));
+            }
+        }
+        etc_value.health_table = etc_value.health_table.replace(&dead, &link);
+        etc_value.health_replacements.push((dead, link));
+    }
+
+    pub(super) fn render_health_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_health;
+        let mut start = false;
+        let mut reupload = None;
+        let mut confirmed = false;
+
+        egui::Window::new("링크 점검 / Link check")
+            .open(&mut open)
+            .default_width(700.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                let etc_value = &mut self.etc_value;
+                let checking = self.receiver.health_rx.is_some();
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut etc_value.health_from_table, false, "업로드 기록");
+                    ui.radio_value(&mut etc_value.health_from_table, true, "붙여 넣은 표");
+                });
+                if etc_value.health_from_table {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut etc_value.health_table)
+                            .hint_text("||<width=15%>Download link||<width=85%>[[https://...]]||")
+                            .desired_rows(6)
+                            .desired_width(f32::INFINITY),
+                    );
+                }
+                if ui
+                    .add_enabled(!checking, egui::Button::new("점검 시작"))
+                    .clicked()
+                {
+                    start = true;
+                }
+                let results = &etc_value.health_results;
+                if checking {
+                    let done = results
+                        .iter()
+                        .filter(|(_, status)| status.is_some())
+                        .count();
+                    ui.label(format!("점검 중... {done} / {}", results.len()));
+                }
+                if let Some(error) = &etc_value.health_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                if let Some((url, file_name)) = etc_value.reupload_confirm.clone() {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        format!(
+                            "{url} 은(는) 업로드 기록에 없어 같은 카드인지 알 수 없습니다. \
+                             불러온 카드({file_name})를 올려 이 링크를 바꿀까요?"
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        if ui.button("올리고 바꾸기").clicked() {
+                            reupload = Some(url);
+                            confirmed = true;
+                        }
+                        if ui.button("취소").clicked() {
+                            etc_value.reupload_confirm = None;
+                        }
+                    });
+                }
+                ui.add_space(PADDING_NARROW);
+
+                let uploading = etc_value.making_download_link;
+                egui::Grid::new("health_grid").striped(true).show(ui, |ui| {
+                    for (url, status) in results {
+                        match status {
+                            None => ui.spinner(),
+                            Some(LinkStatus::Alive) => {
+                                ui.colored_label(egui::Color32::GREEN, "정상")
+                            }
+                            Some(LinkStatus::Dead(reason)) => ui
+                                .colored_label(egui::Color32::RED, "죽음")
+                                .on_hover_text(reason),
+                            Some(LinkStatus::Unreachable(reason)) => ui
+                                .colored_label(egui::Color32::YELLOW, "연결 실패")
+                                .on_hover_text(reason),
+                        };
+                        ui.label(url);
+                        let file_name = self
+                            .settings
+                            .upload_ledger
+                            .find_url(url)
+                            .map(|(_, entry)| entry.file_name.as_str())
+                            .unwrap_or_default();
+                        ui.label(file_name);
+                        if status.as_ref().is_some_and(LinkStatus::is_dead) {
+                            let button = egui::Button::new("다시 올리기");
+                            if ui.add_enabled(!uploading, button).clicked() {
+                                reupload = Some(url.clone());
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                if !etc_value.health_replacements.is_empty() {
+                    ui.add_space(PADDING_WIDE);
+                    ui.label("새로 만든 Download link 줄");
+                    for (_, link) in &etc_value.health_replacements {
+                        let row = self.settings.output_format.download_link_row(link);
+                        ui.horizontal(|ui| {
+                            if ui.small_button("복사").clicked() {
+                                copy_to_clipboard(ui, &row);
+                            }
+                            ui.label(&row);
+                        });
+                    }
+                    if etc_value.health_from_table && ui.button("고친 표 복사").clicked() {
+                        copy_to_clipboard(ui, &etc_value.health_table);
+                    }
+                }
+            });
+        self.etc_value.show_health = open;
+
+        if start {
+            self.start_health_check();
+        }
+        if let Some(url) = reupload {
+            self.reupload_dead_link(&url, confirmed);
+        }
+    }
+}
+
+#[test]
+fn test_reupload_dead_link() -> Result<(), anyhow::Error> {
+    use super::Settings;
+    use uploader::UploadTarget;
+
+    let directory = std::env::temp_dir().join(format!("reupload_{}", std::process::id()));
+    std::fs::create_dir_all(&directory)?;
+    let card = directory.join("Yuzu.png");
+    std::fs::write(&card, b"card")?;
+    let settings = Settings {
+        upload_target: UploadTarget::LocalDirectory {
+            directory: directory.join("uploads").to_string_lossy().to_string(),
+            public_url: "https://example.com/cards".to_string(),
+        },
+        ..Default::default()
+    };
+    let mut frame = BigFrame::with_settings(settings);
+    frame.file_path = Some(card);
+    let dead = "https://files.catbox.moe/dead.png";
+    frame.etc_value.health_table = format!("[[{dead}]]");
+    frame.etc_value.health_results = vec![(dead.to_string(), None)];
+
+    // 기록에 없는 링크는 묻기만 하고 올리지 않음
+    frame.reupload_dead_link(dead, false);
+    assert!(frame.receiver.download_link_rx.is_none());
+    assert_eq!(
+        frame.etc_value.reupload_confirm,
+        Some((dead.to_string(), "Yuzu.png".to_string()))
+    );
+
+    frame.reupload_dead_link(dead, true);
+    let message = frame
+        .receiver
+        .download_link_rx
+        .as_ref()
+        .unwrap()
+        .recv_timeout(std::time::Duration::from_secs(10))?;
+    frame.handle_upload_message(message);
+
+    let link = "https://example.com/cards/Yuzu.png";
+    assert_eq!(frame.etc_value.health_table, format!("[[{link}]]"));
+    assert_eq!(
+        frame.etc_value.health_replacements,
+        [(dead.to_string(), link.to_string())]
+    );
+    assert!(frame.etc_value.reuploading.is_none());
+    std::fs::remove_dir_all(&directory)?;
+    Ok(())
+}
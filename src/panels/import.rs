@@ -0,0 +1,209 @@
+use super::{BigFrame, CharacterItem, DESCRIPTION_LABELS, PADDING_NARROW};
+use eframe::egui;
+use g_translator_m::LANGUAGES;
+use wiki_gen::{parse_namu, Folding, NamuPage, GENERATED_NAME_NOTE};
+
+impl BigFrame {
+    pub(super) fn render_import_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_import;
+        let mut imported = None;
+
+        egui::Window::new("나무위키 가져오기 / Import")
+            .open(&mut open)
+            .default_width(700.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                ui.label("이 도구로 만든 나무위키 표를 붙여 넣으면 항목을 다시 채웁니다.");
+                ui.label("읽지 못한 줄은 그대로 두었다가 다시 만들 때 넣습니다.");
+                ui.add_space(PADDING_NARROW);
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.etc_value.import_text)
+                        .code_editor()
+                        .desired_rows(15)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("||<width=15%>이름 / Name||<width=85%>...||"),
+                );
+                let empty = self.etc_value.import_text.trim().is_empty();
+                if ui
+                    .add_enabled(!empty, egui::Button::new("가져오기"))
+                    .clicked()
+                {
+                    imported = Some(parse_namu(&self.etc_value.import_text));
+                }
+                if let Some(status) = &self.etc_value.import_status {
+                    ui.label(status);
+                }
+            });
+
+        if let Some(page) = imported {
+            self.clear_fields();
+            let (item, known) = character_item_from_namu(&page, &self.settings.folding);
+            // 접힌 첫 메시지와 예시 대화는 접기를 켜야 다시 나옴
+            if !item.first_message.is_empty() || !item.example_dialogue.is_empty() {
+                self.settings.folding.enabled = true;
+            }
+            self.etc_value.import_status = Some(format!(
+                "{known}개 항목을 가져왔습니다. 읽지 못한 줄 {}개는 그대로 둡니다.",
+                item.extra_rows.len() + item.extra_sections.len()
+            ));
+            self.character_item = item;
+        }
+        self.etc_value.show_import = open;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Image,
+    Creator,
+    Name,
+    Tags,
+    DownloadLink,
+    Note,
+    Description(&'static str),
+}
+
+// "제작자 / Creator" 처럼 여러 언어로 쓴 이름 중 하나만 맞아도 됨
+fn row_kind(label: &str) -> Option<Row> {
+    let label = label.trim().to_lowercase();
+    if let Some((code, _)) = DESCRIPTION_LABELS
+        .iter()
+        .find(|(code, name)| name.to_lowercase() == label || label == format!("{code} description"))
+    {
+        return Some(Row::Description(code));
+    }
+    label.split('/').find_map(|part| match part.trim() {
+        "이미지" | "image" => Some(Row::Image),
+        "제작자" | "creator" => Some(Row::Creator),
+        "이름" | "name" => Some(Row::Name),
+        "태그" | "tags" | "tag" => Some(Row::Tags),
+        "다운로드" | "download" | "download link" => Some(Row::DownloadLink),
+        "비고" | "note" => Some(Row::Note),
+        _ => None,
+    })
+}
+
+// [[파일:Yuzu.png|align=center]] 에서 확장자를 뗀 파일명
+fn image_file_name(value: &str) -> String {
+    let target = link_target(value);
+    let target = ["파일:", "File:", "file:"]
+        .iter()
+        .find_map(|prefix| target.strip_prefix(prefix))
+        .unwrap_or(target);
+    target.strip_suffix(".png").unwrap_or(target).to_string()
+}
+
+// [[주소|보이는 글]] 에서 주소만
+fn link_target(value: &str) -> &str {
+    let value = value.trim();
+    let inner = value
+        .strip_prefix("[[")
+        .and_then(|value| value.strip_suffix("]]"))
+        .unwrap_or(value);
+    inner.split('|').next().unwrap_or(inner).trim()
+}
+
+// 자동 생성된 이름에 붙인 각주는 다시 만들 때 새로 붙으므로 뗌
+fn strip_generated_note(name: &str) -> String {
+    let note = format!("[* {GENERATED_NAME_NOTE}]");
+    name.strip_suffix(&note).unwrap_or(name).to_string()
+}
+
+// "첫 메시지 / First message - English" 의 언어 코드
+fn section_language<'a>(title: &'a str, prefix: &str) -> Option<&'a str> {
+    let (kind, language) = title.rsplit_once(" - ")?;
+    if kind.trim() != prefix.trim() {
+        return None;
+    }
+    LANGUAGES
+        .iter()
+        .find(|(_, name)| *name == language.trim())
+        .map(|(code, _)| *code)
+}
+
+// 가져온 항목 수와 함께 돌려줌. 읽지 못한 줄은 extra_rows, extra_sections 에 원래 문법대로
+fn character_item_from_namu(page: &NamuPage, folding: &Folding) -> (CharacterItem, usize) {
+    let mut item = CharacterItem::default();
+    let mut known = 0;
+    for row in &page.rows {
+        let kind = match row.cells {
+            None => row_kind(&row.label),
+            Some(_) => None,
+        };
+        let Some(kind) = kind else {
+            item.extra_rows.push(row.raw.clone());
+            continue;
+        };
+        let value = row.value.clone();
+        match kind {
+            Row::Image => item.file_name = image_file_name(&value),
+            Row::Creator => item.creator = value,
+            Row::Name => item.character_name = strip_generated_note(&value),
+            Row::Tags => item.tags = value,
+            Row::DownloadLink => item.download_link = link_target(&value).to_string(),
+            Row::Note => item.note = value,
+            Row::Description(code) => {
+                item.descriptions.insert(code.to_string(), value);
+            }
+        }
+        known += 1;
+    }
+
+    for section in &page.sections {
+        let title = section.title.as_deref().unwrap_or_default();
+        if let Some(code) = section_language(title, &folding.first_message_title) {
+            item.first_message
+                .insert(code.to_string(), section.text.clone());
+        } else if let Some(code) = section_language(title, &folding.example_dialogue_title) {
+            item.example_dialogue
+                .insert(code.to_string(), section.text.clone());
+        } else {
+            item.extra_sections.push(section.raw.clone());
+            continue;
+        }
+        known += 1;
+    }
+
+    if !page.category.is_empty() {
+        item.category = page.category.clone();
+        known += 1;
+    }
+    (item, known)
+}
+
+#[test]
+fn test_import_namu() {
+    let page = parse_namu(
+        "\
+||<width=15%>이미지||<width=50%>[[파일:Yuzu.png|align=center]]||
+||<width=15%>제작자 / Creator||<width=85%>\\~\\~익명\\~\\~||
+||<width=15%>이름 / Name||<width=85%>유즈 / Yuzu[* 로마자/한글 표기 변환으로 자동 생성된 이름]||
+||<width=15%>태그 / Tags||<width=85%>[[메이드(maid)]]||
+||<width=15%>Download link||<width=85%>[[https://files.catbox.moe/abc123.png]]||
+||<width=15%>비고 / Note||<width=85%>수줍음 / Shy||
+||<width=15%>한글 설명||<width=85%>{{{#!folding 펼치기 / Show
+고양이 메이드
+}}}||
+||<width=15%>English Description||<width=85%>A cat maid||
+||<width=15%>키||<width=85%>150cm||
+{{{#!folding 첫 메시지 / First message - English
+*bows*
+}}}
+== 여담 ==
+[[분류:메이드(Maid)]]",
+    );
+    let (item, known) = character_item_from_namu(&page, &Folding::default());
+    assert_eq!(known, 10);
+    assert_eq!(item.file_name, "Yuzu");
+    assert_eq!(item.creator, "~~익명~~");
+    assert_eq!(item.character_name, "유즈 / Yuzu");
+    assert_eq!(item.tags, "[[메이드(maid)]]");
+    assert_eq!(item.download_link, "https://files.catbox.moe/abc123.png");
+    assert_eq!(item.note, "수줍음 / Shy");
+    assert_eq!(item.descriptions["ko"], "고양이 메이드");
+    assert_eq!(item.descriptions["en"], "A cat maid");
+    assert_eq!(item.first_message["en"], "*bows*");
+    assert_eq!(item.category, "[[분류:메이드(Maid)]]");
+    assert_eq!(item.extra_rows, ["||<width=15%>키||<width=85%>150cm||"]);
+    assert_eq!(item.extra_sections, ["== 여담 =="]);
+}
@@ -0,0 +1,62 @@
+use super::{copy_to_clipboard, BigFrame, PADDING_NARROW};
+use eframe::egui;
+
+impl BigFrame {
+    pub(super) fn render_ledger_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_ledger;
+        let mut removed = None;
+
+        egui::Window::new("업로드 기록 / Ledger")
+            .open(&mut open)
+            .default_width(700.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                ui.label("같은 카드를 같은 곳에 다시 올리면 아래 링크를 대신 씁니다.");
+                ui.horizontal(|ui| {
+                    ui.label("찾기:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.etc_value.ledger_filter)
+                            .hint_text("파일명이나 주소"),
+                    );
+                });
+                ui.add_space(PADDING_NARROW);
+
+                let ledger = &self.settings.upload_ledger;
+                if ledger.is_empty() {
+                    ui.label("아직 올린 파일이 없습니다.");
+                    return;
+                }
+                let filter = self.etc_value.ledger_filter.trim().to_lowercase();
+                egui::Grid::new("ledger_grid").striped(true).show(ui, |ui| {
+                    for (_, entry) in ledger.iter() {
+                        let matches = entry.file_name.to_lowercase().contains(&filter)
+                            || entry.url.to_lowercase().contains(&filter);
+                        if !matches {
+                            continue;
+                        }
+                        ui.label(entry.uploaded_at.format("%Y-%m-%d %H:%M").to_string());
+                        ui.label(&entry.host);
+                        ui.label(&entry.file_name);
+                        if entry.is_expired() {
+                            ui.colored_label(egui::Color32::GRAY, &entry.url)
+                                .on_hover_text("만료됨");
+                        } else {
+                            ui.hyperlink(&entry.url);
+                        }
+                        if ui.small_button("복사").clicked() {
+                            copy_to_clipboard(ui, &entry.url);
+                        }
+                        if ui.small_button("삭제").clicked() {
+                            removed = Some(entry.url.clone());
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+        self.etc_value.show_ledger = open;
+
+        if let Some(url) = removed {
+            self.settings.upload_ledger.remove(&url);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::{BigFrame, PADDING_NARROW, PADDING_WIDE};
+use eframe::egui;
+use g_translator_m::TranslationMemory;
+
+impl BigFrame {
+    pub(super) fn render_memory_window(&mut self, ctx: &egui::Context) {
+        let memory = &mut self.settings.memory;
+        let memory_text = &mut self.etc_value.memory_text;
+
+        egui::Window::new("번역 메모리 / Translation memory")
+            .open(&mut self.etc_value.show_memory)
+            .default_width(600.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                ui.label("기계 번역과 직접 고친 번역을 기록해 두고, 같은 원문은 다시 번역하지 않고 사용합니다.");
+                let edited = memory.segments.iter().filter(|s| s.edited.is_some()).count();
+                ui.label(format!(
+                    "세그먼트 {}개 (직접 고친 번역 {}개)",
+                    memory.segments.len(),
+                    edited
+                ));
+                ui.add_space(PADDING_NARROW);
+                if ui.button("모두 지우기").clicked() {
+                    memory.segments.clear();
+                }
+
+                ui.add_space(PADDING_WIDE);
+                ui.separator();
+                ui.label("TMX/XLIFF 가져오기·내보내기");
+                ui.add_space(PADDING_NARROW);
+
+                ui.horizontal(|ui| {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        if ui.button("파일 가져오기...").clicked() {
+                            if let Some(path) = rfd::FileDialog::new()
+                                .add_filter("translation memory", &["tmx", "xlf", "xliff"])
+                                .pick_file()
+                            {
+                                match std::fs::read_to_string(&path)
+                                    .map_err(anyhow::Error::from)
+                                    .and_then(|data| TranslationMemory::import(&data))
+                                {
+                                    Ok(imported) => memory.merge(imported),
+                                    Err(error) => eprintln!("{error}"),
+                                }
+                            }
+                        }
+                        if ui.button("파일로 내보내기...").clicked() {
+                            if let Some(path) = rfd::FileDialog::new()
+                                .add_filter("TMX", &["tmx"])
+                                .add_filter("XLIFF", &["xlf", "xliff"])
+                                .set_file_name("memory.tmx")
+                                .save_file()
+                            {
+                                let data = match path.extension().and_then(|e| e.to_str()) {
+                                    Some("xlf" | "xliff") => memory.to_xliff(),
+                                    _ => memory.to_tmx(),
+                                };
+                                if let Err(error) =
+                                    data.and_then(|data| Ok(std::fs::write(&path, data)?))
+                                {
+                                    eprintln!("{error}");
+                                }
+                            }
+                        }
+                    }
+
+                    if ui.button("붙여넣은 내용 가져오기").clicked() {
+                        match TranslationMemory::import(memory_text) {
+                            Ok(imported) => memory.merge(imported),
+                            Err(error) => eprintln!("{error}"),
+                        }
+                    }
+                    if ui.button("TMX로 내보내기").clicked() {
+                        if let Ok(data) = memory.to_tmx() {
+                            *memory_text = data;
+                        }
+                    }
+                    if ui.button("XLIFF로 내보내기").clicked() {
+                        if let Ok(data) = memory.to_xliff() {
+                            *memory_text = data;
+                        }
+                    }
+                });
+                ui.add_space(PADDING_NARROW);
+                ui.add(
+                    egui::TextEdit::multiline(memory_text)
+                        .code_editor()
+                        .desired_rows(6)
+                        .desired_width(f32::INFINITY),
+                );
+            });
+    }
+}
@@ -0,0 +1,260 @@
+use super::{
+    description_label, language_order, BigFrame, CharacterItem, FieldSlot, PADDING_NARROW,
+};
+use eframe::egui;
+
+// 검토 창에서 문단마다 붙는 표시
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct ParagraphFlags {
+    pub(super) locked: bool,
+    // 직접 고친 문단
+    pub(super) edited: bool,
+}
+
+impl ParagraphFlags {
+    fn keep(&self) -> bool {
+        self.locked || self.edited
+    }
+}
+
+// 번역기가 줄바꿈을 그대로 두므로 줄 하나를 문단 하나로 봄
+fn paragraphs(text: &str) -> Vec<String> {
+    text.split('\n').map(str::to_string).collect()
+}
+
+// 새 번역으로 바꾸되 잠겼거나 직접 고친 문단은 그대로 둠
+pub(super) fn merge_paragraphs(old: &str, new: &str, flags: &[ParagraphFlags]) -> String {
+    let (old, new) = (paragraphs(old), paragraphs(new));
+    (0..old.len().max(new.len()))
+        .filter_map(|i| match flags.get(i) {
+            Some(flag) if flag.keep() && i < old.len() => Some(old[i].clone()),
+            _ => new.get(i).cloned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+enum ReviewAction {
+    RetranslateAll,
+    Retranslate(usize),
+}
+
+impl CharacterItem {
+    // 설명 전체 번역 결과를 받았을 때, 지켜야 할 문단이 있으면 섞어서 돌려줌
+    pub(super) fn merge_reviewed(&self, language: &str, translated: &str) -> String {
+        match (
+            self.paragraph_flags.get(language),
+            self.descriptions.get(language),
+        ) {
+            (Some(flags), Some(old)) if flags.iter().any(ParagraphFlags::keep) => {
+                merge_paragraphs(old, translated, flags)
+            }
+            _ => translated.to_string(),
+        }
+    }
+
+    // 다시 번역한 문단 하나를 끼워 넣음
+    pub(super) fn apply_paragraph(&mut self, i: usize, language: &str, translated: &str) {
+        let description = self.descriptions.entry(language.to_string()).or_default();
+        let mut lines = paragraphs(description);
+        if lines.len() <= i {
+            lines.resize(i + 1, String::new());
+        }
+        lines[i] = translated.replace('\n', " ");
+        *description = lines.join("\n");
+        let machine = self
+            .machine_descriptions
+            .entry(language.to_string())
+            .or_default();
+        let mut machine_lines = paragraphs(machine);
+        if machine_lines.len() <= i {
+            machine_lines.resize(i + 1, String::new());
+        }
+        machine_lines[i] = lines[i].clone();
+        *machine = machine_lines.join("\n");
+        if let Some(flag) = self
+            .paragraph_flags
+            .get_mut(language)
+            .and_then(|flags| flags.get_mut(i))
+        {
+            flag.edited = false;
+        }
+    }
+
+    // 본문 칸에서 고친 설명을 마지막 번역 결과와 문단별로 비교해 다른 문단을 표시함
+    pub(super) fn mark_edited(&mut self, language: &str) {
+        if language == self.source_language {
+            return;
+        }
+        let machine = self
+            .machine_descriptions
+            .get(language)
+            .map(|text| paragraphs(text))
+            .unwrap_or_default();
+        let current = self
+            .descriptions
+            .get(language)
+            .map(|text| paragraphs(text))
+            .unwrap_or_default();
+        let flags = self
+            .paragraph_flags
+            .entry(language.to_string())
+            .or_default();
+        if flags.len() < current.len() {
+            flags.resize(current.len(), ParagraphFlags::default());
+        }
+        for (i, text) in current.iter().enumerate() {
+            let machine_text = machine.get(i).map(String::as_str).unwrap_or_default();
+            flags[i].edited = text != machine_text;
+        }
+    }
+}
+
+impl BigFrame {
+    fn review_languages(&self) -> Vec<String> {
+        let source = &self.character_item.source_language;
+        let mut languages = self
+            .settings
+            .target_languages
+            .iter()
+            .chain(self.character_item.descriptions.keys())
+            .filter(|language| *language != source)
+            .cloned()
+            .collect::<Vec<_>>();
+        languages.sort_by_key(|language| (language_order(language), language.clone()));
+        languages.dedup();
+        languages
+    }
+
+    pub(super) fn render_review_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_review;
+        let mut action = None;
+        let languages = self.review_languages();
+        if !languages.contains(&self.etc_value.review_language) {
+            self.etc_value.review_language = languages.first().cloned().unwrap_or_default();
+        }
+
+        egui::Window::new("설명 검토 / Review")
+            .open(&mut open)
+            .default_width(800.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                let translating = self.etc_value.making_translation;
+                let language = self.etc_value.review_language.clone();
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source("review_language")
+                        .selected_text(description_label(&language))
+                        .show_ui(ui, |ui| {
+                            for code in &languages {
+                                ui.selectable_value(
+                                    &mut self.etc_value.review_language,
+                                    code.clone(),
+                                    description_label(code),
+                                );
+                            }
+                        });
+                    let button = egui::Button::new("전체 다시 번역");
+                    if ui.add_enabled(!translating, button).clicked() {
+                        action = Some(ReviewAction::RetranslateAll);
+                    }
+                });
+                ui.label("잠그거나 직접 고친 문단은 다시 번역해도 바뀌지 않습니다.");
+                ui.separator();
+
+                let item = &mut self.character_item;
+                let source = item
+                    .descriptions
+                    .get(&item.source_language)
+                    .map(|text| paragraphs(text))
+                    .unwrap_or_default();
+                let description = item.descriptions.entry(language.clone()).or_default();
+                let mut target = paragraphs(description);
+                let rows = source.len().max(target.len());
+                target.resize(rows, String::new());
+                let flags = item.paragraph_flags.entry(language).or_default();
+                flags.resize(rows, ParagraphFlags::default());
+
+                let mut changed = false;
+                for i in 0..rows {
+                    let source_text = source.get(i).map(String::as_str).unwrap_or_default();
+                    if source_text.trim().is_empty() && target[i].trim().is_empty() {
+                        continue;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}", i + 1));
+                        ui.checkbox(&mut flags[i].locked, "잠금");
+                        let button = egui::Button::new("다시 번역");
+                        let enabled = !translating && !flags[i].locked;
+                        if ui.add_enabled(enabled, button).clicked() {
+                            action = Some(ReviewAction::Retranslate(i));
+                        }
+                        if flags[i].edited {
+                            ui.colored_label(egui::Color32::YELLOW, "수정됨");
+                        }
+                    });
+                    ui.columns(2, |columns| {
+                        columns[0].label(source_text);
+                        let text_edit = if translating {
+                            columns[1].add(egui::TextEdit::multiline(&mut target[i].as_str()))
+                        } else {
+                            columns[1].add(egui::TextEdit::multiline(&mut target[i]))
+                        };
+                        if text_edit.changed() {
+                            target[i] = target[i].replace('\n', " ");
+                            flags[i].edited = true;
+                            changed = true;
+                        }
+                    });
+                    ui.add_space(PADDING_NARROW);
+                    ui.separator();
+                }
+                if changed {
+                    *description = target.join("\n");
+                }
+            });
+        self.etc_value.show_review = open;
+
+        let language = self.etc_value.review_language.clone();
+        let item = &self.character_item;
+        let source = item
+            .descriptions
+            .get(&item.source_language)
+            .cloned()
+            .unwrap_or_default();
+        match action {
+            Some(ReviewAction::RetranslateAll) => {
+                self.start_translation(vec![(FieldSlot::Description, source)], vec![language]);
+            }
+            Some(ReviewAction::Retranslate(i)) => {
+                let paragraph = paragraphs(&source).get(i).cloned().unwrap_or_default();
+                let field = FieldSlot::Paragraph(i);
+                self.start_translation(vec![(field, paragraph)], vec![language]);
+            }
+            None => (),
+        }
+    }
+}
+
+#[test]
+fn test_merge_paragraphs() {
+    let flags = [
+        ParagraphFlags::default(),
+        ParagraphFlags {
+            locked: false,
+            edited: true,
+        },
+        ParagraphFlags {
+            locked: true,
+            edited: false,
+        },
+    ];
+    assert_eq!(
+        merge_paragraphs(
+            "old one\nhand edited\nlocked",
+            "new one\nnew two\nnew three",
+            &flags
+        ),
+        "new one\nhand edited\nlocked"
+    );
+    assert_eq!(merge_paragraphs("a\nb", "c\nd\ne", &[]), "c\nd\ne");
+}
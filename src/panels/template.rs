@@ -0,0 +1,107 @@
+use super::{BigFrame, PADDING_NARROW};
+use eframe::egui;
+use wiki_gen::{Format, VARIABLES};
+
+impl BigFrame {
+    pub(super) fn render_template_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.etc_value.show_template;
+        let card = self.card_context();
+
+        egui::Window::new("출력 틀 / Template")
+            .open(&mut open)
+            .default_width(900.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                let settings = &mut self.settings;
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_label("형식")
+                        .selected_text(settings.output_format.label())
+                        .show_ui(ui, |ui| {
+                            for format in Format::ALL {
+                                ui.selectable_value(
+                                    &mut settings.output_format,
+                                    format,
+                                    format.label(),
+                                );
+                            }
+                        });
+                    let format = settings.output_format;
+                    let edited = settings.output_templates.contains_key(&format);
+                    let button = egui::Button::new("기본 틀로 되돌리기");
+                    if ui.add_enabled(edited, button).clicked() {
+                        settings.output_templates.remove(&format);
+                    }
+                });
+                ui.label("minijinja 문법 ({{ 변수 }}, {% for %}, {% if %})을 씁니다.");
+                ui.label("값은 형식에 맞게 이스케이프되며, |safe 를 붙이면 그대로 들어갑니다.");
+                ui.collapsing("변수", |ui| {
+                    egui::Grid::new("template_variables").show(ui, |ui| {
+                        for (name, description) in VARIABLES {
+                            ui.monospace(*name);
+                            ui.label(*description);
+                            ui.end_row();
+                        }
+                    });
+                    ui.label("언어별 목록의 항목은 language, label, text 를 가집니다.");
+                    ui.label("|unlink 는 [[링크]] 에서 보이는 글만 남깁니다.");
+                    ui.label("|fold(제목) 은 나무위키에서 접기를 켰을 때 긴 값을 접습니다.");
+                });
+                if settings.output_format == Format::Namu {
+                    ui.collapsing("접기 / Folding", |ui| {
+                        let folding = &mut settings.folding;
+                        ui.checkbox(
+                            &mut folding.enabled,
+                            "긴 값을 접고, 첫 메시지와 예시 대화를 표 아래에 접어서 넣기",
+                        );
+                        egui::Grid::new("folding_settings").show(ui, |ui| {
+                            ui.label("접는 길이 (글자)");
+                            ui.add(egui::DragValue::new(&mut folding.threshold).speed(10));
+                            ui.end_row();
+                            ui.label("표 안 제목");
+                            ui.text_edit_singleline(&mut folding.title);
+                            ui.end_row();
+                            ui.label("첫 메시지 제목");
+                            ui.text_edit_singleline(&mut folding.first_message_title);
+                            ui.end_row();
+                            ui.label("예시 대화 제목");
+                            ui.text_edit_singleline(&mut folding.example_dialogue_title);
+                            ui.end_row();
+                        });
+                    });
+                }
+                ui.add_space(PADDING_NARROW);
+
+                let format = settings.output_format;
+                let mut template = settings
+                    .output_templates
+                    .get(&format)
+                    .cloned()
+                    .unwrap_or_else(|| format.default_template().to_string());
+                let preview = wiki_gen::render(format, &template, &card);
+                ui.columns(2, |columns| {
+                    columns[0].label("틀");
+                    let editor = egui::TextEdit::multiline(&mut template)
+                        .code_editor()
+                        .desired_rows(20)
+                        .desired_width(f32::INFINITY);
+                    if columns[0].add(editor).changed() {
+                        settings.output_templates.insert(format, template.clone());
+                    }
+                    columns[1].label("미리 보기");
+                    match &preview {
+                        Ok(preview) => {
+                            columns[1].add(
+                                egui::TextEdit::multiline(&mut preview.as_str())
+                                    .desired_rows(20)
+                                    .desired_width(f32::INFINITY),
+                            );
+                        }
+                        Err(e) => {
+                            columns[1].colored_label(egui::Color32::RED, e.to_string());
+                        }
+                    }
+                });
+            });
+        self.etc_value.show_template = open;
+    }
+}
@@ -0,0 +1,214 @@
+use super::{BigFrame, PADDING_NARROW};
+use eframe::egui;
+use uploader::UploadTarget;
+
+const LITTERBOX_TIMES: [&str; 4] = ["1h", "12h", "24h", "72h"];
+// 직접 넣은 주소는 동봉한 프록시가 넘겨주지 않음
+const NO_PROXY_NOTE: &str = "웹에서는 프록시를 거치지 않으므로 서버가 CORS 를 허용해야 합니다.";
+
+// 고를 수 있는 업로드 대상의 기본값
+fn presets() -> Vec<UploadTarget> {
+    #[allow(unused_mut)]
+    let mut presets = vec![
+        UploadTarget::Catbox,
+        UploadTarget::Litterbox {
+            time: "72h".to_string(),
+        },
+        UploadTarget::NullPointer {
+            url: "https://0x0.st".to_string(),
+        },
+        UploadTarget::Multipart {
+            url: String::new(),
+            file_field: "file".to_string(),
+            fields: vec![],
+            url_field: String::new(),
+        },
+        UploadTarget::S3 {
+            endpoint: String::new(),
+            region: "auto".to_string(),
+            bucket: String::new(),
+            prefix: "cards".to_string(),
+            access_key: String::new(),
+            secret_key: String::new(),
+            public_url: String::new(),
+        },
+    ];
+    #[cfg(not(target_arch = "wasm32"))]
+    presets.push(UploadTarget::LocalDirectory {
+        directory: String::new(),
+        public_url: String::new(),
+    });
+    presets
+}
+
+// 폼 필드를 "이름=값" 한 줄씩으로 고침
+fn fields_to_text(fields: &[(String, String)]) -> String {
+    fields
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn text_to_fields(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+impl BigFrame {
+    pub(super) fn render_uploads_window(&mut self, ctx: &egui::Context) {
+        let target = &mut self.settings.upload_target;
+        let userhash = &mut self.settings.catbox_userhash;
+
+        egui::Window::new("업로드 설정 / Upload")
+            .open(&mut self.etc_value.show_uploads)
+            .default_width(500.0)
+            .show(ctx, |ui| {
+                egui::ComboBox::from_label("업로드할 곳")
+                    .selected_text(target.name())
+                    .show_ui(ui, |ui| {
+                        for preset in presets() {
+                            let name = preset.name();
+                            let selected = target.name() == name;
+                            if ui.selectable_label(selected, name).clicked() && !selected {
+                                *target = preset;
+                            }
+                        }
+                    });
+                ui.add_space(PADDING_NARROW);
+
+                match target {
+                    UploadTarget::Catbox => {
+                        ui.label("catbox.moe 에 영구히 올립니다.");
+                        ui.horizontal(|ui| {
+                            ui.label("userhash:");
+                            ui.add(
+                                egui::TextEdit::singleline(userhash)
+                                    .password(true)
+                                    .hint_text("비우면 익명"),
+                            );
+                        });
+                    }
+                    UploadTarget::Litterbox { time } => {
+                        ui.horizontal(|ui| {
+                            ui.label("보관 기간:");
+                            for option in LITTERBOX_TIMES {
+                                ui.selectable_value(time, option.to_string(), option);
+                            }
+                        });
+                    }
+                    UploadTarget::NullPointer { url } => {
+                        ui.add(egui::TextEdit::singleline(url).hint_text("https://0x0.st"));
+                        ui.label(NO_PROXY_NOTE);
+                    }
+                    UploadTarget::Multipart {
+                        url,
+                        file_field,
+                        fields,
+                        url_field,
+                    } => {
+                        egui::Grid::new("multipart_grid").show(ui, |ui| {
+                            ui.label("주소");
+                            ui.add(
+                                egui::TextEdit::singleline(url)
+                                    .hint_text("https://example.com/upload"),
+                            );
+                            ui.end_row();
+                            ui.label("파일 필드");
+                            ui.text_edit_singleline(file_field);
+                            ui.end_row();
+                            ui.label("추가 필드");
+                            let mut text = fields_to_text(fields);
+                            let response = ui.add(
+                                egui::TextEdit::multiline(&mut text)
+                                    .hint_text("name=value")
+                                    .desired_rows(2),
+                            );
+                            if response.changed() {
+                                *fields = text_to_fields(&text);
+                            }
+                            ui.end_row();
+                            ui.label("JSON 주소 필드");
+                            ui.add(
+                                egui::TextEdit::singleline(url_field)
+                                    .hint_text("비우면 응답 본문을 그대로 씀"),
+                            );
+                            ui.end_row();
+                        });
+                        ui.label(NO_PROXY_NOTE);
+                    }
+                    UploadTarget::S3 {
+                        endpoint,
+                        region,
+                        bucket,
+                        prefix,
+                        access_key,
+                        secret_key,
+                        public_url,
+                    } => {
+                        ui.label("같은 카드는 언제나 같은 키(접두어/내용 해시)에 올라갑니다.");
+                        egui::Grid::new("s3_grid").show(ui, |ui| {
+                            ui.label("엔드포인트");
+                            ui.add(
+                                egui::TextEdit::singleline(endpoint)
+                                    .hint_text("http://localhost:9000"),
+                            );
+                            ui.end_row();
+                            ui.label("리전");
+                            ui.add(egui::TextEdit::singleline(region).hint_text("auto"));
+                            ui.end_row();
+                            ui.label("버킷");
+                            ui.text_edit_singleline(bucket);
+                            ui.end_row();
+                            ui.label("접두어");
+                            ui.text_edit_singleline(prefix);
+                            ui.end_row();
+                            ui.label("Access key");
+                            ui.text_edit_singleline(access_key);
+                            ui.end_row();
+                            ui.label("Secret key");
+                            ui.add(egui::TextEdit::singleline(secret_key).password(true));
+                            ui.end_row();
+                            ui.label("공개 주소");
+                            ui.add(
+                                egui::TextEdit::singleline(public_url)
+                                    .hint_text("https://cdn.example.com/{key}"),
+                            );
+                            ui.end_row();
+                        });
+                        ui.label(
+                            "키는 저장하지 않으므로 다시 실행하면 새로 넣어야 합니다. \
+                             데스크톱에서는 비워 두면 AWS_ACCESS_KEY_ID, \
+                             AWS_SECRET_ACCESS_KEY 환경 변수를 씁니다.",
+                        );
+                    }
+                    UploadTarget::LocalDirectory {
+                        directory,
+                        public_url,
+                    } => {
+                        egui::Grid::new("local_directory_grid").show(ui, |ui| {
+                            ui.label("폴더");
+                            ui.horizontal(|ui| {
+                                ui.text_edit_singleline(directory);
+                                #[cfg(not(target_arch = "wasm32"))]
+                                if ui.button("찾기...").clicked() {
+                                    if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                                        *directory = path.display().to_string();
+                                    }
+                                }
+                            });
+                            ui.end_row();
+                            ui.label("공개 주소");
+                            ui.add(
+                                egui::TextEdit::singleline(public_url)
+                                    .hint_text("비우면 file:// 주소"),
+                            );
+                            ui.end_row();
+                        });
+                    }
+                }
+            });
+    }
+}
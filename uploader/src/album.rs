@@ -0,0 +1,106 @@
+use crate::hosts::{post_form, CATBOX_API};
+use crate::progress::check_link;
+use anyhow::{anyhow, Error};
+use reqwest::multipart::Form;
+
+// catbox 계정(userhash)으로 올린 파일과 앨범을 다룸
+pub struct CatboxAccount {
+    pub endpoint: String,
+    pub userhash: String,
+}
+
+// "https://files.catbox.moe/abc123.png" -> "abc123.png"
+pub fn file_id(link: &str) -> &str {
+    let link = link.trim().trim_end_matches('/');
+    link.rsplit('/').next().unwrap_or(link)
+}
+
+// "https://catbox.moe/c/abc123" -> "abc123"
+pub fn album_id(link: &str) -> &str {
+    file_id(link)
+}
+
+impl CatboxAccount {
+    pub fn new(userhash: &str, proxy: Option<&str>) -> Self {
+        Self {
+            endpoint: format!("{}{}", proxy.unwrap_or_default(), CATBOX_API),
+            userhash: userhash.trim().to_string(),
+        }
+    }
+
+    fn form(&self, reqtype: &'static str, files: &[String]) -> Result<Form, Error> {
+        if self.userhash.is_empty() {
+            return Err(anyhow!("A catbox userhash is required"));
+        }
+        let files = files
+            .iter()
+            .map(|link| file_id(link))
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(Form::new()
+            .text("reqtype", reqtype)
+            .text("userhash", self.userhash.clone())
+            .text("files", files))
+    }
+
+    // 새 앨범의 주소를 돌려줌
+    pub async fn create_album(
+        &self,
+        title: &str,
+        description: &str,
+        files: &[String],
+    ) -> Result<String, Error> {
+        let form = self
+            .form("createalbum", files)?
+            .text("title", title.to_string())
+            .text("desc", description.to_string());
+        let body = post_form(&self.endpoint, form).await?;
+        check_link(&body, &["catbox.moe"])
+    }
+
+    pub async fn add_to_album(&self, album: &str, files: &[String]) -> Result<String, Error> {
+        let form = self
+            .form("addtoalbum", files)?
+            .text("short", album_id(album).to_string());
+        post_form(&self.endpoint, form).await
+    }
+
+    pub async fn remove_from_album(&self, album: &str, files: &[String]) -> Result<String, Error> {
+        let form = self
+            .form("removefromalbum", files)?
+            .text("short", album_id(album).to_string());
+        post_form(&self.endpoint, form).await
+    }
+
+    pub async fn delete_files(&self, files: &[String]) -> Result<String, Error> {
+        post_form(&self.endpoint, self.form("deletefiles", files)?).await
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_catbox_album() -> Result<(), Error> {
+    use crate::hosts::mock_server;
+
+    assert_eq!(file_id("https://files.catbox.moe/abc123.png"), "abc123.png");
+    assert_eq!(album_id("https://catbox.moe/c/x1y2z3/"), "x1y2z3");
+
+    let (url, request) = mock_server(200, "https://catbox.moe/c/x1y2z3");
+    let account = CatboxAccount {
+        endpoint: url,
+        userhash: "1234abcd".to_string(),
+    };
+    let files = vec![
+        "https://files.catbox.moe/abc123.png".to_string(),
+        "def456.png".to_string(),
+    ];
+    let album = account.create_album("Yuzu", "", &files).await?;
+    assert_eq!(album, "https://catbox.moe/c/x1y2z3");
+    let request = request.join().unwrap();
+    assert!(request.contains("name=\"reqtype\"\r\n\r\ncreatealbum"));
+    assert!(request.contains("name=\"files\"\r\n\r\nabc123.png def456.png"));
+
+    let anonymous = CatboxAccount::new("", None);
+    assert!(anonymous.delete_files(&files).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::hosts::client;
+use reqwest::StatusCode;
+use shared_constants::PROXY_ERROR_HEADER;
+
+// 응답하지 않는 호스트 하나가 점검 전체를 붙잡지 않도록
+#[cfg(not(target_arch = "wasm32"))]
+const CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
+// 링크 점검 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    Alive,
+    // 호스트가 응답했지만 파일이 없음
+    Dead(String),
+    // 호스트에 닿지 못함. 네트워크 문제일 수 있어 죽은 링크로 보지 않음
+    Unreachable(String),
+}
+
+impl LinkStatus {
+    pub fn is_dead(&self) -> bool {
+        matches!(self, LinkStatus::Dead(_))
+    }
+}
+
+// HEAD 로 링크가 살아 있는지 봄. HEAD 를 받지 않는 호스트는 첫 바이트만 GET 함
+pub async fn check_health(url: &str, proxy: Option<&str>) -> LinkStatus {
+    let url = format!("{}{}", proxy.unwrap_or_default(), url);
+    let mut response = with_timeout(client().head(&url)).send().await;
+    if let Ok(head) = &response {
+        if head.status() == StatusCode::METHOD_NOT_ALLOWED {
+            let get = client()
+                .get(&url)
+                .header(reqwest::header::RANGE, "bytes=0-0");
+            response = with_timeout(get).send().await;
+        }
+    }
+    match response {
+        Ok(response) if response.status().is_success() => LinkStatus::Alive,
+        // 프록시가 넘겨주지 않은 것이라 링크가 죽었는지는 알 수 없음
+        Ok(response) if response.headers().contains_key(PROXY_ERROR_HEADER) => {
+            LinkStatus::Unreachable(format!("Proxy refused: {}", response.status()))
+        }
+        // 파일이 없다고 답한 것만 죽은 링크. 429, 5xx 같은 것은 잠깐일 수 있음
+        Ok(response) if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) => {
+            LinkStatus::Dead(response.status().to_string())
+        }
+        Ok(response) => LinkStatus::Unreachable(response.status().to_string()),
+        Err(e) => LinkStatus::Unreachable(e.to_string()),
+    }
+}
+
+fn with_timeout(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    #[cfg(not(target_arch = "wasm32"))]
+    let request = request.timeout(CHECK_TIMEOUT);
+    request
+}
+
+// 붙여 넣은 위키 표에서 http(s) 링크를 나온 순서대로 뽑음
+pub fn extract_links(text: &str) -> Vec<String> {
+    let mut links: Vec<String> = vec![];
+    let mut rest = text;
+    while let Some(start) = rest.find("http") {
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ']' | '|' | '"' | '\'' | '<' | '>'))
+            .unwrap_or(rest.len());
+        let link = &rest[..end];
+        let link_is_url = link.starts_with("https://") || link.starts_with("http://");
+        if link_is_url && !links.iter().any(|l| l == link) {
+            links.push(link.to_string());
+        }
+        rest = &rest[end.max(4)..];
+    }
+    links
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_check_health() {
+    use crate::hosts::mock_server;
+
+    let (url, request) = mock_server(200, "");
+    assert_eq!(check_health(&url, None).await, LinkStatus::Alive);
+    assert!(request.join().unwrap().starts_with("HEAD "));
+
+    let (url, _) = mock_server(404, "");
+    assert!(check_health(&url, None).await.is_dead());
+
+    let (url, _) = mock_server(503, "");
+    assert!(matches!(
+        check_health(&url, None).await,
+        LinkStatus::Unreachable(_)
+    ));
+
+    let table = "\
+||<width=15%>이미지||<width=50%>[[파일:Yuzu.png|align=center]]||
+||<width=15%>Download link||<width=85%>[[https://files.catbox.moe/abc123.png]]||
+||<width=15%>비고 / Note||<width=85%>https://litter.catbox.moe/x.png 와 [[https://files.catbox.moe/abc123.png]]||";
+    assert_eq!(
+        extract_links(table),
+        vec![
+            "https://files.catbox.moe/abc123.png",
+            "https://litter.catbox.moe/x.png"
+        ]
+    );
+}
@@ -0,0 +1,291 @@
+use crate::progress::check_link;
+use crate::{Progress, Uploader};
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use reqwest::multipart::{Form, Part};
+use std::sync::OnceLock;
+
+pub(crate) const CATBOX_API: &str = "https://catbox.moe/user/api.php";
+pub(crate) const LITTERBOX_API: &str = "https://litterbox.catbox.moe/resources/internals/api.php";
+
+// 모든 업로드가 같이 쓰는 클라이언트. 0x0.st 는 User-Agent 가 없으면 거절함
+pub(crate) fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        let builder = reqwest::Client::builder();
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = builder.user_agent(concat!("CharacterWikiGen/", env!("CARGO_PKG_VERSION")));
+        builder.build().expect("Failed to build the HTTP client")
+    })
+}
+
+// 진행률을 세는 단위
+const PROGRESS_CHUNK: usize = 64 * 1024;
+
+// 네이티브에서는 조각마다 보낸 양을 기록하고, 웹(fetch)은 한 번에 보내므로 끝날 때만 기록함
+pub(crate) fn file_part(data: Vec<u8>, file_name: &str, progress: &Progress) -> Part {
+    progress.start(data.len());
+    #[cfg(not(target_arch = "wasm32"))]
+    let part = {
+        use futures::StreamExt;
+
+        let length = data.len() as u64;
+        let progress = progress.clone();
+        let chunks = data
+            .chunks(PROGRESS_CHUNK)
+            .map(<[u8]>::to_vec)
+            .collect::<Vec<_>>();
+        let stream = futures::stream::iter(chunks).map(move |chunk| {
+            progress.add(chunk.len());
+            Ok::<_, std::io::Error>(chunk)
+        });
+        Part::stream_with_length(reqwest::Body::wrap_stream(stream), length)
+    };
+    #[cfg(target_arch = "wasm32")]
+    let part = Part::bytes(data);
+    part.file_name(file_name.to_string())
+}
+
+// 폼을 보내고 응답 본문을 돌려줌
+pub(crate) async fn post_form(endpoint: &str, form: Form) -> Result<String, Error> {
+    let response = client().post(endpoint).multipart(form).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        let snippet = body.trim().chars().take(200).collect::<String>();
+        return Err(anyhow!("Upload failed with {status}: {snippet}"));
+    }
+    Ok(body.trim().to_string())
+}
+
+pub struct Catbox {
+    pub endpoint: String,
+    // 비어 있으면 익명으로 올림
+    pub userhash: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Uploader for Catbox {
+    fn name(&self) -> &'static str {
+        "catbox.moe"
+    }
+
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error> {
+        let form = Form::new()
+            .text("reqtype", "fileupload")
+            .text("userhash", self.userhash.clone())
+            .part("fileToUpload", file_part(data, file_name, progress));
+        let body = post_form(&self.endpoint, form).await?;
+        progress.finish();
+        check_link(&body, &["files.catbox.moe"])
+    }
+}
+
+// 정해진 시간이 지나면 지워지는 catbox
+pub struct Litterbox {
+    pub endpoint: String,
+    pub time: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Uploader for Litterbox {
+    fn name(&self) -> &'static str {
+        "litterbox"
+    }
+
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error> {
+        let form = Form::new()
+            .text("reqtype", "fileupload")
+            .text("time", self.time.clone())
+            .part("fileToUpload", file_part(data, file_name, progress));
+        let body = post_form(&self.endpoint, form).await?;
+        progress.finish();
+        check_link(&body, &["litter.catbox.moe"])
+    }
+}
+
+pub struct NullPointer {
+    pub endpoint: String,
+    // 링크가 있어야 할 호스트. 프록시를 붙이기 전 주소의 호스트
+    pub host: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Uploader for NullPointer {
+    fn name(&self) -> &'static str {
+        "0x0.st"
+    }
+
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error> {
+        let form = Form::new().part("file", file_part(data, file_name, progress));
+        let body = post_form(&self.endpoint, form).await?;
+        progress.finish();
+        check_link(&body, &[self.host.as_str()])
+    }
+}
+
+pub struct Multipart {
+    pub endpoint: String,
+    pub file_field: String,
+    pub fields: Vec<(String, String)>,
+    pub url_field: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Uploader for Multipart {
+    fn name(&self) -> &'static str {
+        "Multipart"
+    }
+
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error> {
+        let form = self
+            .fields
+            .iter()
+            .fold(Form::new(), |form, (name, value)| {
+                form.text(name.clone(), value.clone())
+            })
+            .part(
+                self.file_field.clone(),
+                file_part(data, file_name, progress),
+            );
+        let body = post_form(&self.endpoint, form).await?;
+        progress.finish();
+        if self.url_field.is_empty() {
+            return check_link(&body, &[]);
+        }
+        let json: serde_json::Value = serde_json::from_str(&body)?;
+        let link = json
+            .get(&self.url_field)
+            .and_then(|url| url.as_str())
+            .ok_or_else(|| anyhow!("The response has no \"{}\" field", self.url_field))?;
+        check_link(link, &[])
+    }
+}
+
+// 요청 하나를 받아 정해진 응답을 돌려주는 테스트용 서버. (주소, 받은 요청) 을 돌려줌
+#[cfg(test)]
+pub(crate) fn mock_server(
+    status: u16,
+    body: &'static str,
+) -> (String, std::thread::JoinHandle<String>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/upload", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = vec![];
+        let mut buffer = [0; 4096];
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..read]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let length = text
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|l| l.trim().to_string())
+                    })
+                    .and_then(|l| l.parse::<usize>().ok())
+                    .unwrap_or(0);
+                if request.len() >= header_end + 4 + length {
+                    break;
+                }
+            }
+            if read == 0 {
+                break;
+            }
+        }
+        let response = format!(
+            "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    });
+    (url, handle)
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_catbox_upload() -> Result<(), Error> {
+    let (url, request) = mock_server(200, "https://files.catbox.moe/abc123.png\n");
+    let uploader = Catbox {
+        endpoint: url,
+        userhash: "1234abcd".to_string(),
+    };
+    let progress = Progress::new();
+    let link = uploader
+        .upload(b"card".to_vec(), "Yuzu.png", &progress)
+        .await?;
+    assert_eq!(link, "https://files.catbox.moe/abc123.png");
+    assert_eq!(progress.fraction(), 1.0);
+    let request = request.join().unwrap();
+    assert!(request.contains("name=\"reqtype\"\r\n\r\nfileupload"));
+    assert!(request.contains("name=\"userhash\"\r\n\r\n1234abcd"));
+    assert!(request.contains("name=\"fileToUpload\"; filename=\"Yuzu.png\""));
+
+    // 200 이어도 링크가 아니면 실패
+    let (url, _) = mock_server(200, "<html>Cloudflare error</html>");
+    let uploader = Catbox {
+        endpoint: url,
+        userhash: String::new(),
+    };
+    let result = uploader.upload(b"card".to_vec(), "Yuzu.png", &progress);
+    assert!(result.await.is_err());
+
+    let (url, _) = mock_server(500, "Internal error");
+    let uploader = NullPointer {
+        endpoint: url,
+        host: "0x0.st".to_string(),
+    };
+    let result = uploader.upload(b"card".to_vec(), "Yuzu.png", &progress);
+    assert!(result.await.is_err());
+    Ok(())
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_multipart_upload() -> Result<(), Error> {
+    let (url, request) = mock_server(200, r#"{"link": "https://example.com/f/Yuzu.png"}"#);
+    let uploader = Multipart {
+        endpoint: url,
+        file_field: "upload".to_string(),
+        fields: vec![("expires".to_string(), "never".to_string())],
+        url_field: "link".to_string(),
+    };
+    let link = uploader
+        .upload(b"card".to_vec(), "Yuzu.png", &Progress::new())
+        .await?;
+    assert_eq!(link, "https://example.com/f/Yuzu.png");
+    let request = request.join().unwrap();
+    assert!(request.contains("name=\"expires\"\r\n\r\nnever"));
+    assert!(request.contains("name=\"upload\"; filename=\"Yuzu.png\""));
+    Ok(())
+}
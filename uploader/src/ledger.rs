@@ -0,0 +1,165 @@
+use crate::UploadTarget;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+// 카드 하나를 한 곳에 올린 기록
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub url: String,
+    // UploadTarget::destination()
+    pub host: String,
+    pub file_name: String,
+    pub uploaded_at: DateTime<Utc>,
+    // litterbox 처럼 지워지는 곳이면 지워지는 때
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl LedgerEntry {
+    // 지금 target 에 올린 기록
+    pub fn new(url: &str, target: &UploadTarget, file_name: &str) -> Self {
+        let now = Utc::now();
+        Self {
+            url: url.to_string(),
+            host: target.destination(),
+            file_name: file_name.to_string(),
+            uploaded_at: now,
+            expires_at: target.lifetime().map(|lifetime| now + lifetime),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+// 파일 내용의 해시 -> 올린 기록. 같은 카드를 다시 올리지 않으려고 씀
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct UploadLedger {
+    entries: BTreeMap<String, Vec<LedgerEntry>>,
+}
+
+pub fn content_hash(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+impl UploadLedger {
+    // 같은 곳에 올린 것 중 아직 살아 있는 가장 최근 기록
+    pub fn lookup(&self, hash: &str, host: &str) -> Option<&LedgerEntry> {
+        self.entries
+            .get(hash)?
+            .iter()
+            .filter(|entry| entry.host == host && !entry.is_expired())
+            .max_by_key(|entry| entry.uploaded_at)
+    }
+
+    pub fn record(&mut self, hash: &str, entry: LedgerEntry) {
+        let entries = self.entries.entry(hash.to_string()).or_default();
+        entries.retain(|old| old.url != entry.url);
+        entries.push(entry);
+    }
+
+    // 링크로 (해시, 기록) 을 찾음
+    pub fn find_url(&self, url: &str) -> Option<(&str, &LedgerEntry)> {
+        self.entries.iter().find_map(|(hash, entries)| {
+            let entry = entries.iter().find(|entry| entry.url == url)?;
+            Some((hash.as_str(), entry))
+        })
+    }
+
+    pub fn remove(&mut self, url: &str) {
+        for entries in self.entries.values_mut() {
+            entries.retain(|entry| entry.url != url);
+        }
+        self.entries.retain(|_, entries| !entries.is_empty());
+    }
+
+    // (해시, 기록) 을 최근에 올린 순서로
+    pub fn iter(&self) -> Vec<(&str, &LedgerEntry)> {
+        let mut entries = self
+            .entries
+            .iter()
+            .flat_map(|(hash, entries)| entries.iter().map(move |entry| (hash.as_str(), entry)))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.uploaded_at));
+        entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// litterbox 의 "1h", "72h" 같은 보관 기간
+pub(crate) fn parse_lifetime(time: &str) -> Option<Duration> {
+    let hours = time.trim().strip_suffix('h')?.parse::<i64>().ok()?;
+    Some(Duration::hours(hours))
+}
+
+#[test]
+fn test_ledger() {
+    let now = Utc::now();
+    let hash = content_hash(b"card");
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, content_hash(b"card"));
+
+    let entry =
+        |url: &str, host: &str, hours_ago: i64, expires_at: Option<DateTime<Utc>>| LedgerEntry {
+            url: url.to_string(),
+            host: host.to_string(),
+            file_name: "Yuzu.png".to_string(),
+            uploaded_at: now - Duration::hours(hours_ago),
+            expires_at,
+        };
+    let mut ledger = UploadLedger::default();
+    ledger.record(
+        &hash,
+        entry("https://files.catbox.moe/old.png", "catbox.moe", 48, None),
+    );
+    ledger.record(
+        &hash,
+        entry("https://files.catbox.moe/new.png", "catbox.moe", 1, None),
+    );
+    let expired = Some(now - Duration::hours(1));
+    ledger.record(
+        &hash,
+        entry("https://litter.catbox.moe/a.png", "litterbox", 2, expired),
+    );
+
+    let found = ledger.lookup(&hash, "catbox.moe").unwrap();
+    assert_eq!(found.url, "https://files.catbox.moe/new.png");
+    assert!(ledger.lookup(&hash, "litterbox").is_none());
+    assert!(ledger
+        .lookup(&content_hash(b"other"), "catbox.moe")
+        .is_none());
+    assert_eq!(ledger.iter()[0].1.url, "https://files.catbox.moe/new.png");
+
+    ledger.remove("https://files.catbox.moe/new.png");
+    let found = ledger.lookup(&hash, "catbox.moe").unwrap();
+    assert_eq!(found.url, "https://files.catbox.moe/old.png");
+
+    let target = UploadTarget::Litterbox {
+        time: "72h".to_string(),
+    };
+    let entry = LedgerEntry::new("https://litter.catbox.moe/b.png", &target, "Yuzu.png");
+    assert_eq!(entry.host, "litterbox");
+    assert_eq!(
+        entry.expires_at,
+        Some(entry.uploaded_at + Duration::hours(72))
+    );
+    assert!(!entry.is_expired());
+
+    // 같은 종류라도 올라가는 곳이 다르면 기록을 같이 쓰지 않음
+    let local = |directory: &str| UploadTarget::LocalDirectory {
+        directory: directory.to_string(),
+        public_url: String::new(),
+    };
+    let (first, second) = (local("/srv/a"), local("/srv/b"));
+    let entry = LedgerEntry::new("file:///srv/a/Yuzu.png", &first, "Yuzu.png");
+    ledger.record(&hash, entry);
+    assert!(ledger.lookup(&hash, &first.destination()).is_some());
+    assert!(ledger.lookup(&hash, &second.destination()).is_none());
+}
@@ -0,0 +1,190 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+mod album;
+mod health;
+mod hosts;
+mod ledger;
+mod local;
+mod progress;
+mod s3;
+pub use album::{album_id, file_id, CatboxAccount};
+pub use health::{check_health, extract_links, LinkStatus};
+pub use hosts::{Catbox, Litterbox, Multipart, NullPointer};
+pub use ledger::{content_hash, LedgerEntry, UploadLedger};
+pub use local::LocalDirectory;
+pub use progress::Progress;
+pub use s3::{object_key, S3};
+
+// 파일 하나를 올리고 내려받을 수 있는 주소를 돌려줌. 보낸 양은 progress 에 기록함
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Uploader: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error>;
+}
+
+// 설정에 저장되는 업로드 대상
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub enum UploadTarget {
+    #[default]
+    Catbox,
+    // time: "1h", "12h", "24h", "72h"
+    Litterbox {
+        time: String,
+    },
+    // 0x0.st 처럼 "file" 필드 하나만 받는 곳
+    NullPointer {
+        url: String,
+    },
+    Multipart {
+        url: String,
+        file_field: String,
+        // 함께 보낼 폼 필드
+        fields: Vec<(String, String)>,
+        // 응답이 JSON 이면 주소가 든 필드 이름, 비어 있으면 응답 본문이 곧 주소
+        url_field: String,
+    },
+    // S3 호환 저장소. 키는 카드 내용의 해시로 정해짐
+    S3 {
+        endpoint: String,
+        region: String,
+        bucket: String,
+        prefix: String,
+        // 웹 빌드는 설정을 localStorage 에 평문으로 남기므로 키는 저장하지 않음
+        #[serde(skip)]
+        access_key: String,
+        #[serde(skip)]
+        secret_key: String,
+        // {bucket}, {key} 가 바뀌는 공개 주소 틀
+        public_url: String,
+    },
+    // 로컬 폴더에 복사하고 정적 서버 주소(public_url)로 링크를 만듦
+    LocalDirectory {
+        directory: String,
+        public_url: String,
+    },
+}
+
+// 비어 있으면 데스크톱에서는 환경 변수에서 읽음
+fn credential(value: &str, variable: &str) -> String {
+    #[cfg(not(target_arch = "wasm32"))]
+    if value.is_empty() {
+        return std::env::var(variable).unwrap_or_default();
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = variable;
+    value.to_string()
+}
+
+impl UploadTarget {
+    // proxy 는 웹 빌드에서 외부 호스트 주소 앞에 붙는 CORS 우회 프록시.
+    // userhash 는 catbox 계정으로 올릴 때만 씀
+    pub fn uploader(&self, proxy: Option<&str>, userhash: &str) -> Box<dyn Uploader> {
+        let proxied = |url: &str| format!("{}{}", proxy.unwrap_or_default(), url);
+        match self {
+            UploadTarget::Catbox => Box::new(Catbox {
+                endpoint: proxied(hosts::CATBOX_API),
+                userhash: userhash.to_string(),
+            }),
+            UploadTarget::Litterbox { time } => Box::new(Litterbox {
+                endpoint: proxied(hosts::LITTERBOX_API),
+                time: time.clone(),
+            }),
+            // 사용자가 넣은 주소는 동봉한 프록시의 허용 목록에 없으므로 직접 보냄.
+            // 웹 빌드에서는 그 서버가 CORS 를 허용해야 함
+            UploadTarget::NullPointer { url } => Box::new(NullPointer {
+                endpoint: url.clone(),
+                host: reqwest::Url::parse(url)
+                    .ok()
+                    .and_then(|url| url.host_str().map(str::to_string))
+                    .unwrap_or_default(),
+            }),
+            UploadTarget::Multipart {
+                url,
+                file_field,
+                fields,
+                url_field,
+            } => Box::new(Multipart {
+                endpoint: url.clone(),
+                file_field: file_field.clone(),
+                fields: fields.clone(),
+                url_field: url_field.clone(),
+            }),
+            // 직접 운영하는 저장소라 프록시를 거치지 않음
+            UploadTarget::S3 {
+                endpoint,
+                region,
+                bucket,
+                prefix,
+                access_key,
+                secret_key,
+                public_url,
+            } => Box::new(S3 {
+                endpoint: endpoint.clone(),
+                region: region.clone(),
+                bucket: bucket.clone(),
+                prefix: prefix.clone(),
+                access_key: credential(access_key, "AWS_ACCESS_KEY_ID"),
+                secret_key: credential(secret_key, "AWS_SECRET_ACCESS_KEY"),
+                public_url: public_url.clone(),
+            }),
+            UploadTarget::LocalDirectory {
+                directory,
+                public_url,
+            } => Box::new(LocalDirectory {
+                directory: directory.into(),
+                public_url: public_url.clone(),
+            }),
+        }
+    }
+
+    // 올린 파일이 지워지기까지의 기간. None 이면 지워지지 않음
+    pub fn lifetime(&self) -> Option<chrono::Duration> {
+        match self {
+            UploadTarget::Litterbox { time } => ledger::parse_lifetime(time),
+            _ => None,
+        }
+    }
+
+    // 올린 기록을 나누는 열쇠. 종류만이 아니라 실제로 올라가는 곳까지 담음
+    pub fn destination(&self) -> String {
+        match self {
+            UploadTarget::Catbox | UploadTarget::Litterbox { .. } => self.name().to_string(),
+            UploadTarget::NullPointer { url } | UploadTarget::Multipart { url, .. } => {
+                url.trim().trim_end_matches('/').to_string()
+            }
+            UploadTarget::S3 {
+                endpoint,
+                bucket,
+                public_url,
+                ..
+            } => format!(
+                "s3:{}/{bucket} {}",
+                endpoint.trim().trim_end_matches('/'),
+                public_url.trim()
+            ),
+            UploadTarget::LocalDirectory {
+                directory,
+                public_url,
+            } => format!("{} {}", directory.trim(), public_url.trim()),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UploadTarget::Catbox => "catbox.moe",
+            UploadTarget::Litterbox { .. } => "litterbox",
+            UploadTarget::NullPointer { .. } => "0x0.st",
+            UploadTarget::Multipart { .. } => "Multipart",
+            UploadTarget::S3 { .. } => "S3",
+            UploadTarget::LocalDirectory { .. } => "Local directory",
+        }
+    }
+}
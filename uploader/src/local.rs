@@ -0,0 +1,73 @@
+use crate::{Progress, Uploader};
+use anyhow::{anyhow, Error};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+// 정적 서버가 보여주는 폴더에 카드를 복사함
+pub struct LocalDirectory {
+    pub directory: PathBuf,
+    // 비어 있으면 file:// 주소를 돌려줌
+    pub public_url: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Uploader for LocalDirectory {
+    fn name(&self) -> &'static str {
+        "Local directory"
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn upload(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        progress: &Progress,
+    ) -> Result<String, Error> {
+        let file_name = std::path::Path::new(file_name)
+            .file_name()
+            .ok_or_else(|| anyhow!("Invalid file name: {file_name}"))?;
+        std::fs::create_dir_all(&self.directory)?;
+        let path = self.directory.join(file_name);
+        progress.start(data.len());
+        std::fs::write(&path, data)?;
+        progress.finish();
+
+        let file_name = file_name.to_string_lossy();
+        if self.public_url.is_empty() {
+            let path = path.canonicalize()?;
+            return reqwest::Url::from_file_path(&path)
+                .map(String::from)
+                .map_err(|_| anyhow!("Can't make a file URL for {}", path.display()));
+        }
+        let base = reqwest::Url::parse(&format!("{}/", self.public_url.trim_end_matches('/')))?;
+        Ok(base.join(&file_name)?.to_string())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    async fn upload(
+        &self,
+        _data: Vec<u8>,
+        _file_name: &str,
+        _progress: &Progress,
+    ) -> Result<String, Error> {
+        Err(anyhow!("Can't write to a local directory in the browser"))
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_local_directory() -> Result<(), Error> {
+    let directory = std::env::temp_dir().join("uploader_test_local_directory");
+    let uploader = LocalDirectory {
+        directory: directory.clone(),
+        public_url: "http://localhost:8000/cards/".to_string(),
+    };
+    let link = uploader
+        .upload(b"card".to_vec(), "Yuzu Maid.png", &Progress::new())
+        .await?;
+    assert_eq!(link, "http://localhost:8000/cards/Yuzu%20Maid.png");
+    assert_eq!(std::fs::read(directory.join("Yuzu Maid.png"))?, b"card");
+    std::fs::remove_dir_all(directory)?;
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anyhow::{anyhow, Error};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+// 업로드하는 동안 GUI 가 매 프레임 읽는 (보낸 바이트, 전체 바이트)
+#[derive(Clone, Default, Debug)]
+pub struct Progress {
+    sent: Arc<AtomicUsize>,
+    total: Arc<AtomicUsize>,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fraction(&self) -> f32 {
+        let total = self.total.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        self.sent.load(Ordering::Relaxed).min(total) as f32 / total as f32
+    }
+
+    pub(crate) fn start(&self, total: usize) {
+        self.total.store(total, Ordering::Relaxed);
+        self.sent.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add(&self, sent: usize) {
+        self.sent.fetch_add(sent, Ordering::Relaxed);
+    }
+
+    pub(crate) fn finish(&self) {
+        self.sent
+            .store(self.total.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+}
+
+// 응답 본문이 기대한 호스트의 https 주소인지 확인함. hosts 가 비어 있으면 호스트는 보지 않음
+pub(crate) fn check_link(body: &str, hosts: &[&str]) -> Result<String, Error> {
+    let body = body.trim();
+    let snippet = body.chars().take(80).collect::<String>();
+    let url = reqwest::Url::parse(body)
+        .map_err(|_| anyhow!("The host did not return a link: {snippet}"))?;
+    if url.scheme() != "https" {
+        return Err(anyhow!("The returned link is not https: {snippet}"));
+    }
+    let host = url.host_str().unwrap_or_default();
+    let expected = hosts
+        .iter()
+        .any(|expected| host == *expected || host.ends_with(&format!(".{expected}")));
+    if !hosts.is_empty() && !expected {
+        return Err(anyhow!(
+            "The returned link is on an unexpected host: {snippet}"
+        ));
+    }
+    Ok(url.to_string())
+}
+
+#[test]
+fn test_check_link() {
+    let hosts = ["files.catbox.moe"];
+    assert_eq!(
+        check_link("https://files.catbox.moe/abc123.png\n", &hosts).unwrap(),
+        "https://files.catbox.moe/abc123.png"
+    );
+    assert!(check_link("<html><body>502 Bad Gateway</body></html>", &hosts).is_err());
+    assert!(check_link("http://files.catbox.moe/abc123.png", &hosts).is_err());
+    assert!(check_link("https://evil.example/abc123.png", &hosts).is_err());
+    assert!(check_link("https://example.com/f/Yuzu.png", &[]).is_ok());
+}
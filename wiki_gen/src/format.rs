@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+// 결과물의 문법. 형식마다 기본 틀과 값 이스케이프가 다름
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Format {
+    #[default]
+    Namu,
+    MediaWiki,
+    Markdown,
+    Html,
+    BBCode,
+}
+
+impl Format {
+    pub const ALL: [Format; 5] = [
+        Format::Namu,
+        Format::MediaWiki,
+        Format::Markdown,
+        Format::Html,
+        Format::BBCode,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Format::Namu => "나무위키",
+            Format::MediaWiki => "MediaWiki",
+            Format::Markdown => "Markdown",
+            Format::Html => "HTML",
+            Format::BBCode => "BBCode",
+        }
+    }
+
+    pub fn default_template(&self) -> &'static str {
+        match self {
+            Format::Namu => include_str!("../templates/namu.jinja"),
+            Format::MediaWiki => include_str!("../templates/mediawiki.jinja"),
+            Format::Markdown => include_str!("../templates/markdown.jinja"),
+            Format::Html => include_str!("../templates/html.jinja"),
+            Format::BBCode => include_str!("../templates/bbcode.jinja"),
+        }
+    }
+
+    // 틀에 들어가는 값을 문법으로 읽히지 않게 바꿈. |safe 를 붙인 값은 그대로 둠
+    pub fn escape(&self, text: &str) -> String {
+        match self {
+            Format::Namu => crate::namu::escape(text),
+            Format::MediaWiki => escape_mediawiki(text),
+            Format::Markdown => escape_markdown(text),
+            Format::Html => escape_html(text),
+            Format::BBCode => escape_bbcode(text),
+        }
+    }
+
+    // 기본 틀의 Download link 줄. 다시 올린 링크로 바꿔 넣을 때 씀
+    pub fn download_link_row(&self, link: &str) -> String {
+        match self {
+            Format::Namu => format!("||<width=15%>Download link||<width=85%>[[{link}]]||"),
+            Format::MediaWiki => format!("|-\n! Download link\n| [{link} {link}]"),
+            Format::Markdown => format!("| Download link | <{link}> |"),
+            Format::Html => {
+                let link = escape_html(link);
+                format!("<tr><th>Download link</th><td><a href=\"{link}\">{link}</a></td></tr>")
+            }
+            Format::BBCode => format!("[b]Download link:[/b] [url]{link}[/url]"),
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 표 칸 안에 들어가므로 줄바꿈은 <br> 로
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '#' | '&' | '!' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("<br>"),
+            '\r' => (),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 줄 첫머리에서만 뜻이 있는 문자도 엔티티로 바꿈
+fn escape_mediawiki(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut line_start = true;
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '[' | ']' | '{' | '}' | '|' | '\'' | '~' => {
+                escaped.push_str(&format!("&#{};", c as u32))
+            }
+            '*' | '#' | ':' | ';' | '=' | '!' | '-' | ' ' if line_start => {
+                escaped.push_str(&format!("&#{};", c as u32))
+            }
+            _ => escaped.push(c),
+        }
+        line_start = c == '\n';
+    }
+    escaped
+}
+
+// BBCode 에는 이스케이프 문법이 없어 [ 뒤에 폭 없는 공백을 넣어 태그로 읽히지 않게 함
+fn escape_bbcode(text: &str) -> String {
+    text.replace('[', "[\u{200B}")
+}
+
+// 나무위키 링크 "[[분류:소녀(Girl)]]", "[[a|b]]" 에서 보이는 글만 남김
+pub(crate) fn unlink(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        let Some(end) = rest[start..].find("]]") else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let inner = &rest[start + 2..start + end];
+        let shown = inner.rsplit('|').next().unwrap_or(inner);
+        let shown = ["분류:", "파일:"]
+            .iter()
+            .find_map(|namespace| shown.strip_prefix(namespace))
+            .unwrap_or(shown);
+        result.push_str(shown);
+        rest = &rest[start + end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[test]
+fn test_escape() {
+    let text = "<b>Tom & 'Jerry'</b> [[link]] | *bold* #1\n* item";
+    assert_eq!(
+        Format::Html.escape(text),
+        "&lt;b&gt;Tom &amp; &#39;Jerry&#39;&lt;/b&gt; [[link]] | *bold* #1\n* item"
+    );
+    assert_eq!(
+        Format::Markdown.escape(text),
+        "\\<b\\>Tom \\& 'Jerry'\\</b\\> \\[\\[link\\]\\] \\| \\*bold\\* \\#1<br>\\* item"
+    );
+    assert_eq!(
+        Format::MediaWiki.escape(text),
+        "&lt;b&gt;Tom &amp; &#39;Jerry&#39;&lt;/b&gt; &#91;&#91;link&#93;&#93; &#124; *bold* #1\n&#42; item"
+    );
+    assert_eq!(
+        Format::BBCode.escape("[b]x[/b]"),
+        "[\u{200B}b]x[\u{200B}/b]"
+    );
+    assert_eq!(
+        unlink("[[고양이수인(Catgirl)]], [[분류:소녀(Girl)]] [[a|b]] [[open"),
+        "고양이수인(Catgirl), 소녀(Girl) b [[open"
+    );
+}
@@ -0,0 +1,214 @@
+use anyhow::{anyhow, Error};
+use minijinja::{Environment, Value};
+use serde::Serialize;
+
+mod format;
+mod namu;
+mod parse;
+pub use format::Format;
+pub use namu::Folding;
+pub use parse::{parse_namu, NamuPage, NamuRow, NamuSection};
+
+// 틀에서 쓰는 언어별 글
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Localized {
+    pub language: String,
+    // "한국어 설명" 처럼 표에 보이는 이름
+    pub label: String,
+    pub text: String,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LorebookEntry {
+    pub keys: String,
+    pub content: Vec<Localized>,
+}
+
+// 이름을 번역하지 못해 표기 변환으로 채웠을 때 결과물에 붙이는 안내
+pub const GENERATED_NAME_NOTE: &str = "로마자/한글 표기 변환으로 자동 생성된 이름";
+
+// 틀에 넘기는 카드. 언어별 목록은 표에 나올 순서로 정렬되어 있음
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Card {
+    pub file_name: String,
+    pub creator: String,
+    // "유즈 / Yuzu" 처럼 이어 붙인 이름
+    pub name: String,
+    pub names: Vec<Localized>,
+    // 번역 대신 로마자/한글 표기 변환으로 채운 이름의 언어 코드
+    pub generated_names: Vec<String>,
+    pub tags: String,
+    pub download_link: String,
+    pub note: String,
+    pub notes: Vec<Localized>,
+    pub descriptions: Vec<Localized>,
+    pub scenario: Vec<Localized>,
+    pub first_message: Vec<Localized>,
+    pub example_dialogue: Vec<Localized>,
+    pub creator_notes: Vec<Localized>,
+    pub alternate_greetings: Vec<Vec<Localized>>,
+    pub lorebook: Vec<LorebookEntry>,
+    pub category: String,
+    // 가져온 나무위키 문서에서 읽지 못한 표의 줄과 표 밖의 글
+    pub extra_rows: Vec<String>,
+    pub extra_sections: Vec<String>,
+    pub folding: Folding,
+}
+
+// 틀 편집 창에 보여 줄 변수 목록
+pub const VARIABLES: &[(&str, &str)] = &[
+    ("file_name", "이미지 파일명"),
+    ("creator", "제작자"),
+    ("name", "이어 붙인 이름"),
+    ("names", "언어별 이름 목록"),
+    ("generated_names", "자동 생성된 이름의 언어 코드 목록"),
+    ("generated_name_note", "자동 생성된 이름에 붙이는 안내"),
+    ("tags", "태그"),
+    ("download_link", "다운로드 링크"),
+    ("note", "이어 붙인 비고"),
+    ("notes", "언어별 비고 목록"),
+    ("descriptions", "언어별 설명 목록"),
+    ("scenario", "언어별 시나리오 목록"),
+    ("first_message", "언어별 첫 메시지 목록"),
+    ("example_dialogue", "언어별 예시 대화 목록"),
+    ("creator_notes", "언어별 제작자 노트 목록"),
+    ("alternate_greetings", "인사말마다 언어별 목록"),
+    ("lorebook", "항목마다 keys 와 언어별 content"),
+    ("category", "분류"),
+    ("extra_rows", "가져온 문서에서 읽지 못한 표의 줄"),
+    ("extra_sections", "가져온 문서의 표 밖의 글"),
+    ("folding", "접기 설정 (enabled, threshold, title, ...)"),
+];
+
+// 틀을 카드로 채움. 틀에 문법 오류가 있으면 줄 번호가 들어간 오류를 돌려줌
+pub fn render(format: Format, template: &str, card: &Card) -> Result<String, Error> {
+    let mut env = Environment::new();
+    env.add_filter("unlink", |text: &str| format::unlink(text));
+    env.add_global("generated_name_note", GENERATED_NAME_NOTE);
+    // 나무위키에서 접기를 켰을 때 threshold 보다 긴 값을 접음. 다른 형식은 그대로 둠
+    let folding = card.folding.clone();
+    env.add_filter(
+        "fold",
+        move |value: Value, title: &str, threshold: Option<usize>| {
+            let Some(text) = value.as_str() else {
+                return value;
+            };
+            let threshold = threshold.unwrap_or(folding.threshold);
+            if format != Format::Namu || !folding.enabled || text.chars().count() <= threshold {
+                return value;
+            }
+            let text = match value.is_safe() {
+                true => text.to_string(),
+                false => format.escape(text),
+            };
+            Value::from_safe_string(namu::folding(&format.escape(title), &text))
+        },
+    );
+    env.set_formatter(move |out, _, value| {
+        match value.as_str() {
+            Some(text) if !value.is_safe() => out.write_str(&format.escape(text))?,
+            _ => write!(out, "{value}")?,
+        }
+        Ok(())
+    });
+    let template = env
+        .template_from_str(template)
+        .map_err(|e| anyhow!("{e:#}"))?;
+    template.render(card).map_err(|e| anyhow!("{e:#}"))
+}
+
+#[test]
+fn test_default_template() -> Result<(), Error> {
+    let localized = |language: &str, label: &str, text: &str| Localized {
+        language: language.to_string(),
+        label: label.to_string(),
+        text: text.to_string(),
+    };
+    let card = Card {
+        file_name: "Yuzu".to_string(),
+        creator: "제작자".to_string(),
+        name: "유즈 / Yuzu".to_string(),
+        tags: "[[메이드(maid)]]".to_string(),
+        download_link: "https://files.catbox.moe/abc123.png".to_string(),
+        note: "수줍음 / Shy".to_string(),
+        descriptions: vec![
+            localized("ko", "한국어 설명", "고양이 메이드"),
+            localized("en", "English Description", "A cat maid"),
+        ],
+        category: "[[분류:메이드(Maid)]]".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        render(Format::Namu, Format::Namu.default_template(), &card)?,
+        "\
+||<width=15%>이미지||<width=50%>[[파일:Yuzu.png|align=center]]||
+||<width=15%>제작자 / Creator||<width=85%>제작자||
+||<width=15%>이름 / Name||<width=85%>유즈 / Yuzu||
+||<width=15%>태그 / Tags||<width=85%>[[메이드(maid)]]||
+||<width=15%>Download link||<width=85%>[[https://files.catbox.moe/abc123.png]]||
+||<width=15%>비고 / Note||<width=85%>수줍음 / Shy||
+||<width=15%>한국어 설명||<width=85%>고양이 메이드||
+||<width=15%>English Description||<width=85%>A cat maid||
+[[분류:메이드(Maid)]]"
+    );
+
+    let greetings = "{% for greeting in alternate_greetings %}{{ loop.index }}. \
+                     {% for text in greeting %}{{ text.text }}{% if not loop.last %} / {% endif %}\
+                     {% endfor %}\n{% endfor %}";
+    let card = Card {
+        alternate_greetings: vec![vec![localized("ko", "", "안녕"), localized("en", "", "Hi")]],
+        ..Default::default()
+    };
+    assert_eq!(render(Format::Namu, greetings, &card)?, "1. 안녕 / Hi\n");
+    assert!(render(Format::Namu, "{% for x in %}", &card).is_err());
+
+    let card = Card {
+        name: "유즈 / Yuzu".to_string(),
+        generated_names: vec!["en".to_string()],
+        ..Default::default()
+    };
+    let namu = render(Format::Namu, Format::Namu.default_template(), &card)?;
+    assert!(namu.contains(&format!("유즈 / Yuzu[* {GENERATED_NAME_NOTE}]||")));
+    let markdown = render(Format::Markdown, Format::Markdown.default_template(), &card)?;
+    assert!(markdown.contains(GENERATED_NAME_NOTE));
+    Ok(())
+}
+
+#[test]
+fn test_formats() -> Result<(), Error> {
+    let card = Card {
+        file_name: "Yuzu".to_string(),
+        creator: "<Tom & Jerry>".to_string(),
+        name: "유즈 / Yuzu".to_string(),
+        tags: "[[고양이수인(Catgirl)]], [[메이드(maid)]]".to_string(),
+        download_link: "https://files.catbox.moe/abc123.png".to_string(),
+        note: "*shy* | [b]cat[/b]".to_string(),
+        category: "[[분류:메이드(Maid)]]".to_string(),
+        ..Default::default()
+    };
+    let render = |format: Format| render(format, format.default_template(), &card);
+
+    let html = render(Format::Html)?;
+    assert!(html.contains("<td>&lt;Tom &amp; Jerry&gt;</td>"));
+    assert!(html.contains("<td>고양이수인(Catgirl), 메이드(maid)</td>"));
+    assert!(html.contains(r#"<a href="https://files.catbox.moe/abc123.png">"#));
+
+    let markdown = render(Format::Markdown)?;
+    assert!(markdown.contains("| 비고 / Note | \\*shy\\* \\| \\[b\\]cat\\[/b\\] |"));
+    assert!(markdown.ends_with("메이드(Maid)"));
+
+    let mediawiki = render(Format::MediaWiki)?;
+    assert!(mediawiki.contains("| &lt;Tom &amp; Jerry&gt;\n"));
+    assert!(mediawiki.contains("| [[고양이수인(Catgirl)]], [[메이드(maid)]]\n"));
+
+    let bbcode = render(Format::BBCode)?;
+    assert!(bbcode.contains("[b]비고 / Note:[/b] *shy* | [\u{200B}b]cat[\u{200B}/b]"));
+    assert!(bbcode.contains("[url]https://files.catbox.moe/abc123.png[/url]"));
+
+    // 링크 점검 창이 만드는 줄은 기본 틀의 줄과 같아야 함
+    for format in Format::ALL {
+        let row = format.download_link_row(&card.download_link);
+        assert!(render(format)?.contains(&row), "{row}");
+    }
+    Ok(())
+}
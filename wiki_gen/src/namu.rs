@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+// 긴 값을 {{{#!folding}}} 으로 접는 설정
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct Folding {
+    pub enabled: bool,
+    // 이 글자 수보다 길면 접음
+    pub threshold: usize,
+    // 표 안에서 접힌 값의 제목
+    pub title: String,
+    pub first_message_title: String,
+    pub example_dialogue_title: String,
+}
+
+impl Default for Folding {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: 1000,
+            title: "펼치기 / Show".to_string(),
+            first_message_title: "첫 메시지 / First message".to_string(),
+            example_dialogue_title: "예시 대화 / Example dialogue".to_string(),
+        }
+    }
+}
+
+// 제목과 값은 이미 이스케이프된 것
+pub(crate) fn folding(title: &str, text: &str) -> String {
+    format!("{{{{{{#!folding {title}\n{text}\n}}}}}}")
+}
+
+// 나무위키 문법에서 두 번 이상 이어질 때 뜻이 생기는 문자
+// (표 ||, 링크 [[ ]], 굵게/기울임 '' ''', 취소선 ~~ --, 밑줄 __, 위/아래 첨자 ^^ ,,)
+const DOUBLED: &[char] = &['|', '[', ']', '\'', '~', '-', '_', '^', ','];
+
+// [br], [include(...)], [* 각주] 같은 매크로로 읽히는지
+fn starts_macro(rest: &[char]) -> bool {
+    if rest.first() == Some(&'*') {
+        return true;
+    }
+    let name = rest.iter().take_while(|c| c.is_alphanumeric()).count();
+    name > 0 && matches!(rest.get(name), Some(']' | '('))
+}
+
+// 값이 문법으로 읽히지 않도록 뜻이 생기는 문자 앞에 \ 를 붙임
+pub(crate) fn escape(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut escaped = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut line_start = true;
+    while i < chars.len() {
+        let c = chars[i];
+        let run = chars[i..].iter().take_while(|next| **next == c).count();
+        let escape_run = match c {
+            '\\' => true,
+            '{' | '}' => run >= 3,
+            '#' => line_start && run >= 2,
+            '=' | '>' => line_start,
+            '[' if starts_macro(&chars[i + 1..]) => true,
+            _ => DOUBLED.contains(&c) && run >= 2,
+        };
+        if escape_run {
+            for _ in 0..run {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            i += run;
+            line_start = false;
+            continue;
+        }
+
+        escaped.push(c);
+        // 목록 " * ", " 1." 은 줄 첫머리의 공백 뒤에서만 뜻이 있음
+        if line_start && c == ' ' {
+            let indent = chars[i..].iter().take_while(|c| **c == ' ').count();
+            escaped.extend(std::iter::repeat_n(' ', indent - 1));
+            i += indent;
+            let list = match chars.get(i) {
+                Some('*') => true,
+                Some(c) if c.is_ascii_digit() => chars[i..]
+                    .iter()
+                    .find(|c| !c.is_ascii_digit())
+                    .is_some_and(|c| *c == '.'),
+                _ => false,
+            };
+            if list {
+                escaped.push('\\');
+            }
+            line_start = false;
+            continue;
+        }
+        line_start = c == '\n';
+        i += 1;
+    }
+    escaped
+}
+
+#[test]
+fn test_escape_golden() {
+    let input = include_str!("../testdata/namu_escape.input.txt");
+    let expected = include_str!("../testdata/namu_escape.expected.txt");
+    for (line, (input, expected)) in input.lines().zip(expected.lines()).enumerate() {
+        let input = input.replace("\\n", "\n");
+        assert_eq!(
+            escape(&input),
+            expected.replace("\\n", "\n"),
+            "line {}",
+            line + 1
+        );
+    }
+    assert_eq!(input.lines().count(), expected.lines().count());
+}
+
+#[test]
+fn test_folding_golden() -> Result<(), anyhow::Error> {
+    use crate::{render, Card, Format, Localized};
+
+    let localized = |label: &str, text: &str| Localized {
+        language: String::new(),
+        label: label.to_string(),
+        text: text.to_string(),
+    };
+    let mut card = Card {
+        file_name: "Yuzu".to_string(),
+        name: "유즈 / Yuzu".to_string(),
+        descriptions: vec![
+            localized("한국어 설명", "짧은 설명"),
+            localized("English Description", "A very long description"),
+        ],
+        first_message: vec![localized("English", "*bows* ~~Welcome~~ home.")],
+        example_dialogue: vec![
+            localized("English", ""),
+            localized("한국어", "{{user}}: 안녕"),
+        ],
+        folding: Folding {
+            enabled: true,
+            threshold: 10,
+            ..Default::default()
+        },
+        category: "[[분류:메이드(Maid)]]".to_string(),
+        ..Default::default()
+    };
+    let expected = include_str!("../testdata/namu_folding.expected.txt");
+    let rendered = render(Format::Namu, Format::Namu.default_template(), &card)?;
+    assert_eq!(rendered, expected.trim_end());
+
+    // 끄면 첫 메시지와 예시 대화가 나오지 않고 표도 그대로
+    card.folding.enabled = false;
+    let rendered = render(Format::Namu, Format::Namu.default_template(), &card)?;
+    assert!(!rendered.contains("#!folding"));
+    assert!(rendered.contains("<width=85%>A very long description||"));
+    Ok(())
+}
+
+#[test]
+fn test_card_golden() -> Result<(), anyhow::Error> {
+    use crate::{render, Card, Format, Localized};
+
+    let card = Card {
+        file_name: "Yuzu".to_string(),
+        creator: "~~익명~~".to_string(),
+        name: "유즈 || Yuzu".to_string(),
+        tags: "[[고양이수인(Catgirl)]], [[메이드(maid)]]".to_string(),
+        download_link: "https://files.catbox.moe/a__b.png".to_string(),
+        note: "## 비밀".to_string(),
+        descriptions: vec![Localized {
+            language: "ko".to_string(),
+            label: "한국어 설명".to_string(),
+            text: "'''수줍은''' 메이드\n{{{#!html <script>}}}\n[[대문]]".to_string(),
+        }],
+        category: "[[분류:메이드(Maid)]]".to_string(),
+        ..Default::default()
+    };
+    let expected = include_str!("../testdata/namu_card.expected.txt");
+    let rendered = render(Format::Namu, Format::Namu.default_template(), &card)?;
+    assert_eq!(rendered, expected.trim_end());
+    Ok(())
+}
@@ -0,0 +1,294 @@
+// 이 도구가 만든 (또는 손으로 고친) 나무위키 문서를 다시 읽음
+
+// 표의 한 줄. label 과 value 는 이스케이프와 접기를 푼 글
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamuRow {
+    pub label: String,
+    pub value: String,
+    // 칸이 둘이 아닌 줄처럼 label/value 로 읽을 수 없으면 None
+    pub cells: Option<usize>,
+    // 줄의 원래 문법. 알 수 없는 줄을 그대로 되돌릴 때 씀
+    pub raw: String,
+}
+
+// 표 밖의 덩어리. 접기면 title 이 있음
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamuSection {
+    pub title: Option<String>,
+    pub text: String,
+    pub raw: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamuPage {
+    pub rows: Vec<NamuRow>,
+    pub sections: Vec<NamuSection>,
+    // [[분류:...]] 만 있는 줄을 이어 붙인 것
+    pub category: String,
+}
+
+pub fn parse_namu(markup: &str) -> NamuPage {
+    let mut page = NamuPage::default();
+    let mut lines = markup.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        // 칸 안의 줄바꿈이나 접기 때문에 여러 줄에 걸칠 수 있음
+        let mut block = line.to_string();
+        let is_row = trimmed.starts_with("||");
+        let complete = |block: &str| match is_row {
+            true => split_cells(block).is_some(),
+            false => braces_depth(block) <= 0,
+        };
+        while !complete(&block) {
+            let Some(next) = lines.next() else { break };
+            block.push('\n');
+            block.push_str(next);
+        }
+
+        if is_row {
+            page.rows.push(parse_row(block));
+        } else if is_category(trimmed) {
+            if !page.category.is_empty() {
+                page.category.push('\n');
+            }
+            page.category.push_str(trimmed);
+        } else {
+            page.sections.push(parse_section(block));
+        }
+    }
+    page
+}
+
+fn parse_row(raw: String) -> NamuRow {
+    let cells = split_cells(&raw).unwrap_or_default();
+    let mut row = NamuRow {
+        raw,
+        ..Default::default()
+    };
+    if let [label, value] = &cells[..] {
+        row.label = unescape(strip_attributes(label)).replace("'''", "");
+        let value = strip_attributes(value);
+        row.value = match unfold(value) {
+            Some((_, text)) => unescape(text),
+            None => unescape(value),
+        };
+    } else {
+        row.cells = Some(cells.len());
+    }
+    row
+}
+
+fn parse_section(raw: String) -> NamuSection {
+    match unfold(raw.trim()) {
+        Some((title, text)) => NamuSection {
+            title: Some(unescape(title)),
+            text: unescape(text),
+            raw,
+        },
+        None => NamuSection {
+            title: None,
+            text: unescape(raw.trim()),
+            raw,
+        },
+    }
+}
+
+fn is_category(line: &str) -> bool {
+    line.starts_with("[[분류:")
+        && line.ends_with("]]")
+        && line
+            .split("]]")
+            .all(|part| part.trim().is_empty() || part.trim().starts_with("[[분류:"))
+}
+
+// 이스케이프되지 않은 {{{ 와 }}} 의 차이
+fn braces_depth(text: &str) -> isize {
+    let mut depth = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' | '}' => {
+                let run = 1 + std::iter::from_fn(|| chars.next_if_eq(&c)).count();
+                let triples = (run / 3) as isize;
+                depth += if c == '{' { triples } else { -triples };
+            }
+            _ => (),
+        }
+    }
+    depth
+}
+
+// "||a||b||" 를 ["a", "b"] 로. 줄이 아직 끝나지 않았으면 None
+fn split_cells(row: &str) -> Option<Vec<String>> {
+    let row = row.trim();
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut depth = 0;
+    let mut chars = row.chars().peekable();
+    let mut delimiters = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                cell.extend(chars.next());
+            }
+            '|' if depth == 0 && chars.peek() == Some(&'|') => {
+                chars.next();
+                delimiters += 1;
+                cells.push(std::mem::take(&mut cell));
+            }
+            '{' | '}' => {
+                cell.push(c);
+                let run = 1 + std::iter::from_fn(|| chars.next_if_eq(&c)).count();
+                cell.extend(std::iter::repeat_n(c, run - 1));
+                let triples = (run / 3) as isize;
+                depth += if c == '{' { triples } else { -triples };
+            }
+            _ => cell.push(c),
+        }
+    }
+    if depth > 0 || delimiters < 2 || !cell.is_empty() {
+        return None;
+    }
+    // 첫 || 앞은 비어 있음
+    cells.remove(0);
+    Some(cells)
+}
+
+// 칸 앞의 <width=15%>, <:>, <-2>, <#fff> 같은 속성을 뗌
+fn strip_attributes(cell: &str) -> &str {
+    let mut cell = cell;
+    while let Some(rest) = cell.strip_prefix('<') {
+        let Some(end) = rest.find('>') else { break };
+        let attribute = &rest[..end];
+        let known = match attribute.split_once('=') {
+            Some((key, _)) => !key.is_empty() && key.chars().all(|c| c.is_ascii_alphabetic()),
+            None => {
+                matches!(attribute, ":" | "(" | ")")
+                    || attribute
+                        .trim_start_matches(['-', '|', '^', 'v'])
+                        .chars()
+                        .all(|c| c.is_ascii_digit())
+                    || attribute
+                        .strip_prefix('#')
+                        .is_some_and(|color| color.chars().all(|c| c.is_ascii_alphanumeric()))
+            }
+        };
+        if !known || attribute.is_empty() {
+            break;
+        }
+        cell = &rest[end + 1..];
+    }
+    cell.trim()
+}
+
+// {{{#!folding 제목\n내용}}} 을 (제목, 내용) 으로
+fn unfold(text: &str) -> Option<(&str, &str)> {
+    let inner = text.strip_prefix("{{{#!folding")?.strip_suffix("}}}")?;
+    let (title, body) = inner.split_once('\n').unwrap_or((inner, ""));
+    Some((title.trim(), body.strip_suffix('\n').unwrap_or(body)))
+}
+
+// escape 를 되돌림. 손으로 쓴 [br] 은 줄바꿈으로
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            unescaped.extend(chars.next());
+        } else if c == '[' && chars.as_str().starts_with("br]") {
+            chars.nth(2);
+            unescaped.push('\n');
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
+
+#[test]
+fn test_parse_golden() -> Result<(), anyhow::Error> {
+    use crate::namu::escape;
+
+    let page = parse_namu(include_str!("../testdata/namu_folding.expected.txt"));
+    let rows = page
+        .rows
+        .iter()
+        .map(|row| (row.label.as_str(), row.value.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        [
+            ("이미지", "[[파일:Yuzu.png|align=center]]"),
+            ("제작자 / Creator", ""),
+            ("이름 / Name", "유즈 / Yuzu"),
+            ("태그 / Tags", ""),
+            ("Download link", "[[]]"),
+            ("비고 / Note", ""),
+            ("한국어 설명", "짧은 설명"),
+            ("English Description", "A very long description"),
+        ]
+    );
+    let sections = page
+        .sections
+        .iter()
+        .map(|section| (section.title.as_deref(), section.text.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sections,
+        [
+            (
+                Some("첫 메시지 / First message - English"),
+                "*bows* ~~Welcome~~ home."
+            ),
+            (
+                Some("예시 대화 / Example dialogue - 한국어"),
+                "{{user}}: 안녕"
+            ),
+        ]
+    );
+    assert_eq!(page.category, "[[분류:메이드(Maid)]]");
+
+    let page = parse_namu(include_str!("../testdata/namu_card.expected.txt"));
+    assert_eq!(page.rows[2].value, "유즈 || Yuzu");
+    assert_eq!(
+        page.rows[6].value,
+        "'''수줍은''' 메이드\n{{{#!html <script>}}}\n[[대문]]"
+    );
+
+    // 손으로 고친 표: 속성이 다르거나 없고, 칸이 셋인 줄과 표 밖의 글이 있음
+    let page = parse_namu(
+        "\
+||<bgcolor=#fff><:>'''이름'''|| 유즈[br]Yuzu ||
+|| 제작자 || <Tom & Jerry> ||
+||<-2> 정보 ||
+||키||160cm||50kg||
+== 개요 ==
+고양이 메이드",
+    );
+    assert_eq!(page.rows[0].label, "이름");
+    assert_eq!(page.rows[0].value, "유즈\nYuzu");
+    assert_eq!(page.rows[1].value, "<Tom & Jerry>");
+    assert_eq!(page.rows[2].cells, Some(1));
+    assert_eq!(page.rows[3].cells, Some(3));
+    assert_eq!(page.rows[3].raw, "||키||160cm||50kg||");
+    assert_eq!(page.sections.len(), 2);
+    assert_eq!(page.sections[1].text, "고양이 메이드");
+
+    for text in [
+        "A || B",
+        "'''굵게''' ~~취소~~",
+        "## 주석\n{{{#!html}}}",
+        "C:\\Users",
+    ] {
+        assert_eq!(unescape(&escape(text)), text);
+    }
+    Ok(())
+}